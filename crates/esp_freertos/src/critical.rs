@@ -0,0 +1,20 @@
+use esp_idf_freertos_sys as sys;
+
+use crate::PortMux;
+
+// a single mux is shared by every `critical_section::with` call, nested calls re-enter it
+static CRITICAL_SECTION: PortMux = PortMux::new();
+
+struct EspCriticalSection;
+
+critical_section::set_impl!(EspCriticalSection);
+
+unsafe impl critical_section::Impl for EspCriticalSection {
+  unsafe fn acquire() -> critical_section::RawRestoreState {
+    sys::vTaskEnterCritical(CRITICAL_SECTION.as_raw());
+  }
+
+  unsafe fn release(_restore_state: critical_section::RawRestoreState) {
+    sys::vTaskExitCritical(CRITICAL_SECTION.as_raw());
+  }
+}
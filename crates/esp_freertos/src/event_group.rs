@@ -0,0 +1,151 @@
+use core::{mem::MaybeUninit, time::Duration};
+
+use esp_idf_freertos_sys as sys;
+use esp_idf_system::EspError;
+
+use crate::to_timeout;
+
+/// Bits that can be used in an [EventGroup], the upper 8 bits are reserved by FreeRTOS
+pub const EVENT_GROUP_BITS: u32 = 0x00ff_ffff;
+
+/// Static storage for an [EventGroup], see [EventGroup::new_static]
+pub struct EventGroupStorage {
+  group: MaybeUninit<EventGroup>,
+  raw: MaybeUninit<sys::StaticEventGroup_t>,
+}
+
+impl EventGroupStorage {
+  pub const fn new() -> Self {
+    EventGroupStorage {
+      group: MaybeUninit::uninit(),
+      raw: MaybeUninit::uninit(),
+    }
+  }
+}
+
+impl Default for EventGroupStorage {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+/// A set of event bits that tasks can wait on
+///
+/// Only the bits in [EVENT_GROUP_BITS] can be used.
+pub struct EventGroup {
+  handle: sys::EventGroupHandle_t,
+}
+
+unsafe impl Send for EventGroup {}
+unsafe impl Sync for EventGroup {}
+
+impl EventGroup {
+  /// Create an event group with all bits cleared, allocated on the FreeRTOS heap
+  pub fn new() -> Result<Self, EspError> {
+    let handle = unsafe { sys::xEventGroupCreate() };
+    if handle.is_null() {
+      return Err(EspError::NoMem);
+    }
+
+    Ok(EventGroup { handle })
+  }
+
+  /// Create an event group with all bits cleared in static storage
+  pub fn new_static(storage: &'static mut EventGroupStorage) -> Result<&'static Self, EspError> {
+    unsafe {
+      let handle = sys::xEventGroupCreateStatic(storage.raw.as_mut_ptr());
+      if handle.is_null() {
+        return Err(EspError::InvalidArg);
+      }
+
+      storage.group.as_mut_ptr().write(EventGroup { handle });
+      Ok(&*storage.group.as_ptr())
+    }
+  }
+
+  /// Set bits, waking tasks waiting for them. Returns the bits after setting.
+  pub fn set_bits(&self, bits: u32) -> u32 {
+    debug_assert_eq!(bits & !EVENT_GROUP_BITS, 0, "reserved event group bits");
+    unsafe { sys::xEventGroupSetBits(self.handle, bits) }
+  }
+
+  /// Clear bits, returns the bits before clearing
+  pub fn clear_bits(&self, bits: u32) -> u32 {
+    debug_assert_eq!(bits & !EVENT_GROUP_BITS, 0, "reserved event group bits");
+    unsafe { sys::xEventGroupClearBits(self.handle, bits) }
+  }
+
+  /// Current bits
+  pub fn bits(&self) -> u32 {
+    unsafe { sys::xEventGroupClearBits(self.handle, 0) }
+  }
+
+  /// Set bits from an interrupt handler
+  ///
+  /// Setting the bits is deferred to the FreeRTOS timer task. Returns `false` if its queue is
+  /// full.
+  pub fn set_bits_from_isr(&self, bits: u32, woken: &mut bool) -> bool {
+    debug_assert_eq!(bits & !EVENT_GROUP_BITS, 0, "reserved event group bits");
+    let mut higher_priority_woken = sys::pdFALSE;
+    let posted =
+      unsafe { sys::xEventGroupSetBitsFromISR(self.handle, bits, &mut higher_priority_woken) };
+
+    *woken |= higher_priority_woken == sys::pdTRUE;
+    posted == sys::pdPASS
+  }
+
+  /// Current bits, from an interrupt handler
+  pub fn bits_from_isr(&self) -> u32 {
+    unsafe { sys::xEventGroupGetBitsFromISR(self.handle) }
+  }
+
+  fn wait(&self, bits: u32, clear_on_exit: bool, all: bool, timeout: Option<Duration>) -> u32 {
+    debug_assert_eq!(bits & !EVENT_GROUP_BITS, 0, "reserved event group bits");
+    unsafe {
+      sys::xEventGroupWaitBits(
+        self.handle,
+        bits,
+        clear_on_exit as sys::BaseType_t,
+        all as sys::BaseType_t,
+        to_timeout(timeout),
+      )
+    }
+  }
+
+  /// Wait up to `timeout` for any of `bits` to be set
+  ///
+  /// Returns the group's bits when the wait ended, or `None` on timeout. If `clear_on_exit` is
+  /// `true`, the awaited bits are cleared before returning.
+  pub fn wait_any(&self, bits: u32, clear_on_exit: bool, timeout: Option<Duration>) -> Option<u32> {
+    let result = self.wait(bits, clear_on_exit, false, timeout);
+    if result & bits != 0 {
+      Some(result)
+    } else {
+      None
+    }
+  }
+
+  /// Wait up to `timeout` for all of `bits` to be set
+  ///
+  /// Returns the group's bits when the wait ended, or `None` on timeout. If `clear_on_exit` is
+  /// `true`, the awaited bits are cleared before returning.
+  pub fn wait_all(&self, bits: u32, clear_on_exit: bool, timeout: Option<Duration>) -> Option<u32> {
+    let result = self.wait(bits, clear_on_exit, true, timeout);
+    if result & bits == bits {
+      Some(result)
+    } else {
+      None
+    }
+  }
+
+  /// The raw event group handle
+  pub fn as_raw(&self) -> sys::EventGroupHandle_t {
+    self.handle
+  }
+}
+
+impl Drop for EventGroup {
+  fn drop(&mut self) {
+    unsafe { sys::vEventGroupDelete(self.handle) }
+  }
+}
@@ -0,0 +1,97 @@
+//! Safe wrappers around the FreeRTOS kernel shipped with ESP-IDF.
+//!
+//! ```ignore
+//! let handle = Task::builder()
+//!   .name("worker")
+//!   .stack_size(8 * 1024)
+//!   .core(Core::App)
+//!   .spawn(|| compute())?;
+//! let result = handle.join();
+//! ```
+//!
+//! The synchronization primitives ([Queue], [Semaphore], [Mutex] and [EventGroup]) can be
+//! allocated on the FreeRTOS heap, or created in caller-provided static storage so they can be
+//! shared between tasks without `alloc`:
+//!
+//! ```ignore
+//! static mut EVENTS_BUFFER: [MaybeUninit<Event>; 8] = [MaybeUninit::uninit(); 8];
+//! static mut EVENTS_STORAGE: QueueStorage<Event> = QueueStorage::new();
+//!
+//! let events = Queue::new_static(unsafe { &mut EVENTS_BUFFER }, unsafe { &mut EVENTS_STORAGE })?;
+//! events.send(Event::Started, None)?;
+//! ```
+//!
+//! Timeouts are given as `Option<Duration>`, `None` waits forever.
+//!
+//! Functions ending in `_from_isr` may be called from interrupt handlers. They set `woken` to
+//! `true` if a higher priority task was unblocked, in which case the handler should end with
+//! [yield_from_isr].
+//!
+//! ## Features
+//!
+//! * `critical-section` - registers an implementation for the `critical-section` crate, based
+//!   on a global [PortMux]
+//! * `lock_api` - adds [RawMutex], a `lock_api::RawMutex` backed by a FreeRTOS mutex
+
+#![no_std]
+
+use core::time::Duration;
+
+use esp_idf_freertos_sys as sys;
+
+#[cfg(feature = "critical-section")]
+mod critical;
+mod event_group;
+mod mutex;
+mod notify;
+mod queue;
+#[cfg(feature = "lock_api")]
+mod raw_mutex;
+mod semaphore;
+mod task;
+
+pub use esp_idf_system::{Core, CriticalSectionGuard, PortMux};
+pub use event_group::*;
+pub use mutex::*;
+pub use notify::*;
+pub use queue::*;
+#[cfg(feature = "lock_api")]
+pub use raw_mutex::*;
+pub use semaphore::*;
+pub use task::*;
+
+/// Convert a duration to scheduler ticks, rounding up
+///
+/// Durations too long to represent block forever.
+pub(crate) fn to_ticks(duration: Duration) -> sys::TickType_t {
+  let hz = sys::configTICK_RATE_HZ as u128;
+  let nanos = duration.as_nanos() * hz;
+  let mut ticks = nanos / 1_000_000_000;
+  if ticks * 1_000_000_000 < nanos {
+    ticks += 1;
+  }
+
+  if ticks >= sys::portMAX_DELAY as u128 {
+    sys::portMAX_DELAY
+  } else {
+    ticks as sys::TickType_t
+  }
+}
+
+/// Convert a timeout to scheduler ticks, `None` blocks forever
+pub(crate) fn to_timeout(timeout: Option<Duration>) -> sys::TickType_t {
+  match timeout {
+    Some(timeout) => to_ticks(timeout),
+    None => sys::portMAX_DELAY,
+  }
+}
+
+/// Request a context switch when leaving the interrupt handler, if `woken` is `true`
+///
+/// Call this at the end of an interrupt handler that used `_from_isr` functions, so that a
+/// higher priority task they unblocked runs right away.
+pub fn yield_from_isr(woken: bool) {
+  if woken {
+    unsafe { sys::vPortYieldFromInt() }
+  }
+}
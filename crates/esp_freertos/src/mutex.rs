@@ -0,0 +1,178 @@
+use core::{
+  cell::UnsafeCell, fmt, marker::PhantomData, mem::MaybeUninit, ops::Deref, time::Duration,
+};
+
+use esp_idf_freertos_sys as sys;
+use esp_idf_system::EspError;
+
+use crate::to_timeout;
+
+/// Static storage for a [Mutex], see [Mutex::new_static]
+pub struct MutexStorage<T> {
+  mutex: MaybeUninit<Mutex<T>>,
+  raw: MaybeUninit<sys::StaticQueue_t>,
+}
+
+impl<T> MutexStorage<T> {
+  pub const fn new() -> Self {
+    MutexStorage {
+      mutex: MaybeUninit::uninit(),
+      raw: MaybeUninit::uninit(),
+    }
+  }
+}
+
+impl<T> Default for MutexStorage<T> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+/// A recursive mutex protecting a value, backed by a FreeRTOS recursive mutex
+///
+/// The task holding the lock can lock it again without deadlocking. As a consequence the guard
+/// only gives shared access to the value, use a `Cell` or `RefCell` inside the mutex to modify
+/// it. FreeRTOS mutexes use priority inheritance and can not be used from interrupt handlers.
+///
+/// ```ignore
+/// let config = Mutex::new(RefCell::new(Config::default()))?;
+/// config.lock().borrow_mut().volume = 3;
+/// ```
+pub struct Mutex<T> {
+  handle: sys::QueueHandle_t,
+  data: UnsafeCell<T>,
+}
+
+unsafe impl<T: Send> Send for Mutex<T> {}
+unsafe impl<T: Send> Sync for Mutex<T> {}
+
+impl<T> Mutex<T> {
+  /// Create a mutex, allocated on the FreeRTOS heap
+  pub fn new(value: T) -> Result<Self, EspError> {
+    let handle = unsafe { sys::xQueueCreateMutex(sys::queueQUEUE_TYPE_RECURSIVE_MUTEX) };
+    if handle.is_null() {
+      return Err(EspError::NoMem);
+    }
+
+    Ok(Mutex {
+      handle,
+      data: UnsafeCell::new(value),
+    })
+  }
+
+  /// Create a mutex in static storage
+  ///
+  /// The mutex lives forever, so it can be shared between tasks.
+  pub fn new_static(
+    value: T,
+    storage: &'static mut MutexStorage<T>,
+  ) -> Result<&'static Self, EspError> {
+    unsafe {
+      let handle = sys::xQueueCreateMutexStatic(
+        sys::queueQUEUE_TYPE_RECURSIVE_MUTEX,
+        storage.raw.as_mut_ptr(),
+      );
+      if handle.is_null() {
+        return Err(EspError::InvalidArg);
+      }
+
+      storage.mutex.as_mut_ptr().write(Mutex {
+        handle,
+        data: UnsafeCell::new(value),
+      });
+      Ok(&*storage.mutex.as_ptr())
+    }
+  }
+
+  /// Lock the mutex, blocking until it is available
+  pub fn lock(&self) -> MutexGuard<'_, T> {
+    unsafe { sys::xQueueTakeMutexRecursive(self.handle, sys::portMAX_DELAY) };
+    MutexGuard {
+      mutex: self,
+      _not_send: PhantomData,
+    }
+  }
+
+  /// Lock the mutex, waiting up to `timeout` for it to become available
+  pub fn try_lock_for(&self, timeout: Option<Duration>) -> Option<MutexGuard<'_, T>> {
+    let taken = unsafe { sys::xQueueTakeMutexRecursive(self.handle, to_timeout(timeout)) };
+    if taken == sys::pdTRUE {
+      Some(MutexGuard {
+        mutex: self,
+        _not_send: PhantomData,
+      })
+    } else {
+      None
+    }
+  }
+
+  /// Lock the mutex if it is available without waiting
+  pub fn try_lock(&self) -> Option<MutexGuard<'_, T>> {
+    self.try_lock_for(Some(Duration::from_secs(0)))
+  }
+
+  /// Mutable access to the value, no locking is needed as the borrow is exclusive
+  pub fn get_mut(&mut self) -> &mut T {
+    unsafe { &mut *self.data.get() }
+  }
+
+  /// Consume the mutex, returning the value
+  pub fn into_inner(self) -> T {
+    let this = core::mem::ManuallyDrop::new(self);
+    unsafe {
+      sys::vQueueDelete(this.handle);
+      core::ptr::read(this.data.get())
+    }
+  }
+
+  /// The raw mutex handle
+  pub fn as_raw(&self) -> sys::QueueHandle_t {
+    self.handle
+  }
+}
+
+impl<T> Drop for Mutex<T> {
+  fn drop(&mut self) {
+    unsafe { sys::vQueueDelete(self.handle) }
+  }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Mutex<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self.try_lock() {
+      Some(guard) => f.debug_struct("Mutex").field("data", &&*guard).finish(),
+      None => f.debug_struct("Mutex").field("data", &"<locked>").finish(),
+    }
+  }
+}
+
+/// Lock on a [Mutex], unlocked when dropped
+///
+/// The lock belongs to the task that took it, so the guard can not be sent to other tasks.
+#[must_use = "the mutex is unlocked when the guard is dropped"]
+pub struct MutexGuard<'a, T> {
+  mutex: &'a Mutex<T>,
+  _not_send: PhantomData<*const ()>,
+}
+
+unsafe impl<T: Sync> Sync for MutexGuard<'_, T> {}
+
+impl<T> Deref for MutexGuard<'_, T> {
+  type Target = T;
+
+  fn deref(&self) -> &T {
+    unsafe { &*self.mutex.data.get() }
+  }
+}
+
+impl<T> Drop for MutexGuard<'_, T> {
+  fn drop(&mut self) {
+    unsafe { sys::xQueueGiveMutexRecursive(self.mutex.handle) };
+  }
+}
+
+impl<T: fmt::Debug> fmt::Debug for MutexGuard<'_, T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt::Debug::fmt(&**self, f)
+  }
+}
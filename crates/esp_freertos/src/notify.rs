@@ -0,0 +1,108 @@
+use core::time::Duration;
+
+use esp_idf_freertos_sys as sys;
+
+use crate::{to_timeout, Task};
+
+/// How a task notification updates the notification value of the receiving task
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NotifyAction {
+  /// Notify without changing the value
+  NoAction,
+  /// OR bits into the value
+  SetBits(u32),
+  /// Increment the value, like giving a counting semaphore
+  Increment,
+  /// Set the value, overwriting a pending notification
+  SetValue(u32),
+  /// Set the value, unless a notification is pending
+  SetValueWithoutOverwrite(u32),
+}
+
+impl NotifyAction {
+  fn to_raw(self) -> (sys::eNotifyAction, u32) {
+    match self {
+      Self::NoAction => (sys::eNotifyAction_eNoAction, 0),
+      Self::SetBits(bits) => (sys::eNotifyAction_eSetBits, bits),
+      Self::Increment => (sys::eNotifyAction_eIncrement, 0),
+      Self::SetValue(value) => (sys::eNotifyAction_eSetValueWithOverwrite, value),
+      Self::SetValueWithoutOverwrite(value) => {
+        (sys::eNotifyAction_eSetValueWithoutOverwrite, value)
+      }
+    }
+  }
+}
+
+impl Task {
+  /// Send a notification to the task
+  ///
+  /// Returns `false` only for [NotifyAction::SetValueWithoutOverwrite] when a notification was
+  /// already pending.
+  ///
+  /// # Safety
+  ///
+  /// The task must exist, see [Task].
+  pub unsafe fn notify(&self, action: NotifyAction) -> bool {
+    let (action, value) = action.to_raw();
+    sys::xTaskGenericNotify(self.as_raw(), value, action, core::ptr::null_mut()) == sys::pdPASS
+  }
+
+  /// Send a notification to the task from an interrupt handler
+  ///
+  /// Returns `false` only for [NotifyAction::SetValueWithoutOverwrite] when a notification was
+  /// already pending.
+  ///
+  /// # Safety
+  ///
+  /// The task must exist, see [Task].
+  pub unsafe fn notify_from_isr(&self, action: NotifyAction, woken: &mut bool) -> bool {
+    let (action, value) = action.to_raw();
+    let mut higher_priority_woken = sys::pdFALSE;
+    let notified = sys::xTaskGenericNotifyFromISR(
+      self.as_raw(),
+      value,
+      action,
+      core::ptr::null_mut(),
+      &mut higher_priority_woken,
+    );
+
+    *woken |= higher_priority_woken == sys::pdTRUE;
+    notified == sys::pdPASS
+  }
+}
+
+/// Wait up to `timeout` for a notification to the current task
+///
+/// Bits in `clear_on_entry` are cleared from the notification value before waiting, bits in
+/// `clear_on_exit` after a notification was received. Returns the notification value, or `None`
+/// on timeout.
+pub fn notify_wait(
+  clear_on_entry: u32,
+  clear_on_exit: u32,
+  timeout: Option<Duration>,
+) -> Option<u32> {
+  let mut value = 0;
+  let received = unsafe {
+    sys::xTaskNotifyWait(
+      clear_on_entry,
+      clear_on_exit,
+      &mut value,
+      to_timeout(timeout),
+    )
+  };
+
+  if received == sys::pdTRUE {
+    Some(value)
+  } else {
+    None
+  }
+}
+
+/// Wait up to `timeout` for the notification value of the current task to become non-zero,
+/// using it like a semaphore
+///
+/// The value is cleared if `clear` is `true`, decremented otherwise. Returns the value before it
+/// was cleared or decremented, 0 on timeout.
+pub fn notify_take(clear: bool, timeout: Option<Duration>) -> u32 {
+  unsafe { sys::ulTaskNotifyTake(clear as sys::BaseType_t, to_timeout(timeout)) }
+}
@@ -0,0 +1,238 @@
+use core::{
+  convert::TryFrom,
+  marker::PhantomData,
+  mem::{self, MaybeUninit},
+  ptr,
+  time::Duration,
+};
+
+use esp_idf_freertos_sys as sys;
+use esp_idf_system::EspError;
+
+use crate::to_timeout;
+
+/// Static storage for a [Queue], see [Queue::new_static]
+pub struct QueueStorage<T> {
+  queue: MaybeUninit<Queue<T>>,
+  raw: MaybeUninit<sys::StaticQueue_t>,
+}
+
+impl<T> QueueStorage<T> {
+  pub const fn new() -> Self {
+    QueueStorage {
+      queue: MaybeUninit::uninit(),
+      raw: MaybeUninit::uninit(),
+    }
+  }
+}
+
+impl<T> Default for QueueStorage<T> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+fn queue_len(len: usize) -> Result<sys::UBaseType_t, EspError> {
+  match sys::UBaseType_t::try_from(len) {
+    Ok(len) if len > 0 => Ok(len),
+    _ => Err(EspError::InvalidArg),
+  }
+}
+
+/// A FIFO queue for sending values between tasks and interrupt handlers
+///
+/// Values are copied into the queue's buffer, so sending and receiving moves them.
+pub struct Queue<T> {
+  handle: sys::QueueHandle_t,
+  _marker: PhantomData<T>,
+}
+
+unsafe impl<T: Send> Send for Queue<T> {}
+unsafe impl<T: Send> Sync for Queue<T> {}
+
+impl<T: Send> Queue<T> {
+  /// Create a queue holding up to `len` values, allocated on the FreeRTOS heap
+  ///
+  /// Returns [EspError::InvalidArg] if `len` is zero or does not fit a `UBaseType_t`.
+  pub fn new(len: usize) -> Result<Self, EspError> {
+    let len = queue_len(len)?;
+    let handle =
+      unsafe { sys::xQueueGenericCreate(len, mem::size_of::<T>() as _, sys::queueQUEUE_TYPE_BASE) };
+
+    if handle.is_null() {
+      return Err(EspError::NoMem);
+    }
+
+    Ok(Queue {
+      handle,
+      _marker: PhantomData,
+    })
+  }
+
+  /// Create a queue in static storage, holding up to `buffer.len()` values
+  ///
+  /// The queue lives forever, so it can be shared between tasks. Returns [EspError::InvalidArg]
+  /// if the buffer is empty.
+  pub fn new_static(
+    buffer: &'static mut [MaybeUninit<T>],
+    storage: &'static mut QueueStorage<T>,
+  ) -> Result<&'static Self, EspError> {
+    let len = queue_len(buffer.len())?;
+    // the buffer is ignored for zero-sized values, but must be NULL
+    let buffer_ptr = if mem::size_of::<T>() == 0 {
+      ptr::null_mut()
+    } else {
+      buffer.as_mut_ptr() as *mut u8
+    };
+
+    let handle = unsafe {
+      sys::xQueueGenericCreateStatic(
+        len,
+        mem::size_of::<T>() as _,
+        buffer_ptr,
+        storage.raw.as_mut_ptr(),
+        sys::queueQUEUE_TYPE_BASE,
+      )
+    };
+
+    if handle.is_null() {
+      return Err(EspError::InvalidArg);
+    }
+
+    unsafe {
+      storage.queue.as_mut_ptr().write(Queue {
+        handle,
+        _marker: PhantomData,
+      });
+      Ok(&*storage.queue.as_ptr())
+    }
+  }
+
+  fn send_to(
+    &self,
+    item: T,
+    timeout: Option<Duration>,
+    position: sys::BaseType_t,
+  ) -> Result<(), T> {
+    let sent = unsafe {
+      sys::xQueueGenericSend(
+        self.handle,
+        &item as *const T as *const _,
+        to_timeout(timeout),
+        position,
+      )
+    };
+
+    if sent == sys::pdTRUE {
+      mem::forget(item);
+      Ok(())
+    } else {
+      Err(item)
+    }
+  }
+
+  /// Send a value to the back of the queue, waiting up to `timeout` for space
+  ///
+  /// The value is returned if the queue stayed full.
+  pub fn send(&self, item: T, timeout: Option<Duration>) -> Result<(), T> {
+    self.send_to(item, timeout, sys::queueSEND_TO_BACK)
+  }
+
+  /// Send a value to the front of the queue, waiting up to `timeout` for space
+  ///
+  /// The value is returned if the queue stayed full.
+  pub fn send_to_front(&self, item: T, timeout: Option<Duration>) -> Result<(), T> {
+    self.send_to(item, timeout, sys::queueSEND_TO_FRONT)
+  }
+
+  /// Receive a value, waiting up to `timeout` for one to arrive
+  pub fn receive(&self, timeout: Option<Duration>) -> Option<T> {
+    let mut item = MaybeUninit::<T>::uninit();
+    let received = unsafe {
+      sys::xQueueReceive(
+        self.handle,
+        item.as_mut_ptr() as *mut _,
+        to_timeout(timeout),
+      )
+    };
+
+    if received == sys::pdTRUE {
+      Some(unsafe { item.assume_init() })
+    } else {
+      None
+    }
+  }
+
+  /// Send a value to the back of the queue from an interrupt handler
+  ///
+  /// The value is returned if the queue is full.
+  pub fn send_from_isr(&self, item: T, woken: &mut bool) -> Result<(), T> {
+    let mut higher_priority_woken = sys::pdFALSE;
+    let sent = unsafe {
+      sys::xQueueGenericSendFromISR(
+        self.handle,
+        &item as *const T as *const _,
+        &mut higher_priority_woken,
+        sys::queueSEND_TO_BACK,
+      )
+    };
+
+    *woken |= higher_priority_woken == sys::pdTRUE;
+    if sent == sys::pdTRUE {
+      mem::forget(item);
+      Ok(())
+    } else {
+      Err(item)
+    }
+  }
+
+  /// Receive a value from an interrupt handler, `None` if the queue is empty
+  pub fn receive_from_isr(&self, woken: &mut bool) -> Option<T> {
+    let mut item = MaybeUninit::<T>::uninit();
+    let mut higher_priority_woken = sys::pdFALSE;
+    let received = unsafe {
+      sys::xQueueReceiveFromISR(
+        self.handle,
+        item.as_mut_ptr() as *mut _,
+        &mut higher_priority_woken,
+      )
+    };
+
+    *woken |= higher_priority_woken == sys::pdTRUE;
+    if received == sys::pdTRUE {
+      Some(unsafe { item.assume_init() })
+    } else {
+      None
+    }
+  }
+
+  /// Number of values in the queue
+  pub fn len(&self) -> usize {
+    unsafe { sys::uxQueueMessagesWaiting(self.handle) as usize }
+  }
+
+  /// Returns `true` if the queue holds no values
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// The raw queue handle
+  pub fn as_raw(&self) -> sys::QueueHandle_t {
+    self.handle
+  }
+}
+
+impl<T> Drop for Queue<T> {
+  fn drop(&mut self) {
+    unsafe {
+      if mem::needs_drop::<T>() {
+        let mut item = MaybeUninit::<T>::uninit();
+        while sys::xQueueReceive(self.handle, item.as_mut_ptr() as *mut _, 0) == sys::pdTRUE {
+          ptr::drop_in_place(item.as_mut_ptr());
+        }
+      }
+
+      sys::vQueueDelete(self.handle);
+    }
+  }
+}
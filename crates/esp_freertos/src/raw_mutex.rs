@@ -0,0 +1,91 @@
+use core::{
+  ptr,
+  sync::atomic::{AtomicPtr, Ordering},
+};
+
+use esp_idf_freertos_sys as sys;
+
+/// A `lock_api::RawMutex` backed by a FreeRTOS mutex
+///
+/// Blocked tasks sleep instead of spinning, and the task holding the lock inherits the
+/// priority of tasks waiting for it. The FreeRTOS mutex is allocated when the mutex is first
+/// used, so `lock_api::Mutex::new` can be used in statics. Can not be used from interrupt
+/// handlers, use [PortMux] there instead.
+///
+/// ```ignore
+/// static CONFIG: lock_api::Mutex<RawMutex, Config> = lock_api::Mutex::const_new(RawMutex::INIT, Config::new());
+///
+/// CONFIG.lock().volume = 3;
+/// ```
+///
+/// [PortMux]: crate::PortMux
+pub struct RawMutex {
+  handle: AtomicPtr<sys::QueueDefinition>,
+}
+
+impl RawMutex {
+  fn handle(&self) -> sys::QueueHandle_t {
+    let handle = self.handle.load(Ordering::Acquire);
+    if !handle.is_null() {
+      return handle;
+    }
+
+    let created = unsafe { sys::xQueueCreateMutex(sys::queueQUEUE_TYPE_MUTEX) };
+    if created.is_null() {
+      panic!("failed to allocate FreeRTOS mutex");
+    }
+
+    match self.handle.compare_exchange(
+      ptr::null_mut(),
+      created,
+      Ordering::AcqRel,
+      Ordering::Acquire,
+    ) {
+      Ok(_) => created,
+      Err(existing) => {
+        // another task created the mutex first
+        unsafe { sys::vQueueDelete(created) };
+        existing
+      }
+    }
+  }
+
+  fn take(&self, ticks: sys::TickType_t) -> bool {
+    unsafe { sys::xQueueSemaphoreTake(self.handle(), ticks) == sys::pdTRUE }
+  }
+}
+
+unsafe impl lock_api::RawMutex for RawMutex {
+  const INIT: Self = RawMutex {
+    handle: AtomicPtr::new(ptr::null_mut()),
+  };
+
+  // the mutex must be given back by the task that took it
+  type GuardMarker = lock_api::GuardNoSend;
+
+  fn lock(&self) {
+    self.take(sys::portMAX_DELAY);
+  }
+
+  fn try_lock(&self) -> bool {
+    self.take(0)
+  }
+
+  unsafe fn unlock(&self) {
+    sys::xQueueGenericSend(
+      self.handle(),
+      ptr::null(),
+      sys::semGIVE_BLOCK_TIME,
+      sys::queueSEND_TO_BACK,
+    );
+  }
+}
+
+impl Drop for RawMutex {
+  fn drop(&mut self) {
+    let handle = *self.handle.get_mut();
+    if !handle.is_null() {
+      unsafe { sys::vQueueDelete(handle) }
+    }
+  }
+}
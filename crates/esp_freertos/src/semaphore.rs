@@ -0,0 +1,179 @@
+use core::{mem::MaybeUninit, ptr, time::Duration};
+
+use esp_idf_freertos_sys as sys;
+use esp_idf_system::EspError;
+
+use crate::to_timeout;
+
+/// Static storage for a [Semaphore], see [Semaphore::new_binary_static] and
+/// [Semaphore::new_counting_static]
+pub struct SemaphoreStorage {
+  semaphore: MaybeUninit<Semaphore>,
+  raw: MaybeUninit<sys::StaticQueue_t>,
+}
+
+impl SemaphoreStorage {
+  pub const fn new() -> Self {
+    SemaphoreStorage {
+      semaphore: MaybeUninit::uninit(),
+      raw: MaybeUninit::uninit(),
+    }
+  }
+}
+
+impl Default for SemaphoreStorage {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+/// A binary or counting semaphore
+///
+/// Unlike [Mutex](crate::Mutex) there is no owner, any task or interrupt handler can give the
+/// semaphore, e.g. to signal an event.
+pub struct Semaphore {
+  handle: sys::QueueHandle_t,
+}
+
+unsafe impl Send for Semaphore {}
+unsafe impl Sync for Semaphore {}
+
+impl Semaphore {
+  fn from_handle(handle: sys::QueueHandle_t) -> Result<Self, EspError> {
+    if handle.is_null() {
+      Err(EspError::NoMem)
+    } else {
+      Ok(Semaphore { handle })
+    }
+  }
+
+  fn place(
+    handle: sys::QueueHandle_t,
+    storage: &'static mut SemaphoreStorage,
+  ) -> Result<&'static Self, EspError> {
+    if handle.is_null() {
+      return Err(EspError::InvalidArg);
+    }
+
+    unsafe {
+      storage.semaphore.as_mut_ptr().write(Semaphore { handle });
+      Ok(&*storage.semaphore.as_ptr())
+    }
+  }
+
+  /// Create a binary semaphore, allocated on the FreeRTOS heap. It starts out taken.
+  pub fn new_binary() -> Result<Self, EspError> {
+    Self::from_handle(unsafe {
+      sys::xQueueGenericCreate(
+        1,
+        sys::semSEMAPHORE_QUEUE_ITEM_LENGTH,
+        sys::queueQUEUE_TYPE_BINARY_SEMAPHORE,
+      )
+    })
+  }
+
+  /// Create a counting semaphore, allocated on the FreeRTOS heap
+  ///
+  /// Returns [EspError::InvalidArg] if `initial` is larger than `max` or `max` is zero.
+  pub fn new_counting(max: u32, initial: u32) -> Result<Self, EspError> {
+    if max == 0 || initial > max {
+      return Err(EspError::InvalidArg);
+    }
+
+    Self::from_handle(unsafe { sys::xQueueCreateCountingSemaphore(max, initial) })
+  }
+
+  /// Create a binary semaphore in static storage. It starts out taken.
+  pub fn new_binary_static(
+    storage: &'static mut SemaphoreStorage,
+  ) -> Result<&'static Self, EspError> {
+    let handle = unsafe {
+      sys::xQueueGenericCreateStatic(
+        1,
+        sys::semSEMAPHORE_QUEUE_ITEM_LENGTH,
+        ptr::null_mut(),
+        storage.raw.as_mut_ptr(),
+        sys::queueQUEUE_TYPE_BINARY_SEMAPHORE,
+      )
+    };
+
+    Self::place(handle, storage)
+  }
+
+  /// Create a counting semaphore in static storage
+  ///
+  /// Returns [EspError::InvalidArg] if `initial` is larger than `max` or `max` is zero.
+  pub fn new_counting_static(
+    max: u32,
+    initial: u32,
+    storage: &'static mut SemaphoreStorage,
+  ) -> Result<&'static Self, EspError> {
+    if max == 0 || initial > max {
+      return Err(EspError::InvalidArg);
+    }
+
+    let handle =
+      unsafe { sys::xQueueCreateCountingSemaphoreStatic(max, initial, storage.raw.as_mut_ptr()) };
+
+    Self::place(handle, storage)
+  }
+
+  /// Take the semaphore, waiting up to `timeout` for it to become available
+  ///
+  /// Returns `false` on timeout.
+  pub fn take(&self, timeout: Option<Duration>) -> bool {
+    unsafe { sys::xQueueSemaphoreTake(self.handle, to_timeout(timeout)) == sys::pdTRUE }
+  }
+
+  /// Give the semaphore
+  ///
+  /// Returns `false` if the semaphore is already available (binary) or at its maximum count.
+  pub fn give(&self) -> bool {
+    unsafe {
+      sys::xQueueGenericSend(
+        self.handle,
+        ptr::null(),
+        sys::semGIVE_BLOCK_TIME,
+        sys::queueSEND_TO_BACK,
+      ) == sys::pdTRUE
+    }
+  }
+
+  /// Take the semaphore from an interrupt handler, returns `false` if it is not available
+  pub fn take_from_isr(&self, woken: &mut bool) -> bool {
+    let mut higher_priority_woken = sys::pdFALSE;
+    let taken = unsafe {
+      sys::xQueueReceiveFromISR(self.handle, ptr::null_mut(), &mut higher_priority_woken)
+    };
+
+    *woken |= higher_priority_woken == sys::pdTRUE;
+    taken == sys::pdTRUE
+  }
+
+  /// Give the semaphore from an interrupt handler
+  ///
+  /// Returns `false` if the semaphore is already available (binary) or at its maximum count.
+  pub fn give_from_isr(&self, woken: &mut bool) -> bool {
+    let mut higher_priority_woken = sys::pdFALSE;
+    let given = unsafe { sys::xQueueGiveFromISR(self.handle, &mut higher_priority_woken) };
+
+    *woken |= higher_priority_woken == sys::pdTRUE;
+    given == sys::pdTRUE
+  }
+
+  /// Current count, 0 or 1 for binary semaphores
+  pub fn count(&self) -> u32 {
+    unsafe { sys::uxQueueMessagesWaiting(self.handle) }
+  }
+
+  /// The raw semaphore handle
+  pub fn as_raw(&self) -> sys::QueueHandle_t {
+    self.handle
+  }
+}
+
+impl Drop for Semaphore {
+  fn drop(&mut self) {
+    unsafe { sys::vQueueDelete(self.handle) }
+  }
+}
@@ -0,0 +1,368 @@
+use core::{
+  cell::UnsafeCell,
+  ffi::c_void,
+  fmt,
+  marker::PhantomData,
+  mem, ptr,
+  sync::atomic::{AtomicU8, Ordering},
+  time::Duration,
+};
+
+use esp_idf_freertos_sys as sys;
+use esp_idf_system::{
+  heap::{CapsBox, HeapCaps},
+  CStr, Core, EspError,
+};
+
+use crate::to_ticks;
+
+/// Stack size of spawned tasks, in bytes, unless set with [TaskBuilder::stack_size]
+pub const DEFAULT_STACK_SIZE: u32 = 4096;
+
+/// Smallest stack size accepted by [TaskBuilder::spawn], in bytes
+///
+/// This is `configMINIMAL_STACK_SIZE` plus room for the frame that runs the closure. The size of
+/// the closure and of its result are added to it.
+pub const MIN_STACK_SIZE: u32 = sys::configMINIMAL_STACK_SIZE + 256;
+
+/// Priority of spawned tasks unless set with [TaskBuilder::priority]
+pub const DEFAULT_PRIORITY: u32 = 1;
+
+/// Number of task priorities, valid priorities are `0..MAX_PRIORITIES`
+pub const MAX_PRIORITIES: u32 = sys::configMAX_PRIORITIES;
+
+// configMAX_TASK_NAME_LEN, including the NUL terminator
+const MAX_NAME_LEN: usize = 16;
+
+/// Handle to a FreeRTOS task
+///
+/// The handle does not keep the task alive, spawned tasks are deleted once their closure
+/// returns. Operations on other tasks are therefore `unsafe`, and must only be performed while
+/// the task exists. The handle of the current task is always valid.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Task(sys::TaskHandle_t);
+
+unsafe impl Send for Task {}
+unsafe impl Sync for Task {}
+
+impl Task {
+  /// Create a builder for spawning a new task
+  pub fn builder() -> TaskBuilder {
+    TaskBuilder::new()
+  }
+
+  /// Wrap a raw task handle
+  ///
+  /// # Safety
+  ///
+  /// `handle` must refer to an existing task.
+  pub unsafe fn from_raw(handle: sys::TaskHandle_t) -> Self {
+    Task(handle)
+  }
+
+  /// The raw task handle
+  pub fn as_raw(&self) -> sys::TaskHandle_t {
+    self.0
+  }
+
+  /// Name of the task
+  ///
+  /// # Safety
+  ///
+  /// The task must exist, and must not be deleted while the name is in use.
+  pub unsafe fn name(&self) -> &str {
+    let name = CStr::from_ptr(sys::pcTaskGetTaskName(self.0));
+    core::str::from_utf8(name.to_bytes()).unwrap_or("")
+  }
+
+  /// Suspend the task until [Task::resume] is called
+  ///
+  /// Suspending the current task blocks until another task resumes it.
+  ///
+  /// # Safety
+  ///
+  /// The task must exist.
+  pub unsafe fn suspend(&self) {
+    sys::vTaskSuspend(self.0)
+  }
+
+  /// Resume a suspended task
+  ///
+  /// # Safety
+  ///
+  /// The task must exist.
+  pub unsafe fn resume(&self) {
+    sys::vTaskResume(self.0)
+  }
+
+  /// The minimum amount of free stack space, in bytes, since the task started
+  ///
+  /// The closer to zero, the closer the task came to overflowing its stack.
+  ///
+  /// # Safety
+  ///
+  /// The task must exist.
+  pub unsafe fn stack_high_water_mark(&self) -> usize {
+    sys::uxTaskGetStackHighWaterMark(self.0) as usize
+  }
+}
+
+impl fmt::Debug for Task {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    // the task might not exist anymore, so its name can not be read
+    f.debug_tuple("Task").field(&self.0).finish()
+  }
+}
+
+/// Handle of the task that is currently running
+pub fn current_task() -> Task {
+  Task(unsafe { sys::xTaskGetCurrentTaskHandle() })
+}
+
+/// Block the current task for at least `duration`
+///
+/// The delay is rounded up to whole scheduler ticks.
+pub fn delay(duration: Duration) {
+  unsafe { sys::vTaskDelay(to_ticks(duration)) }
+}
+
+/// Yield to other tasks of the same priority
+pub fn yield_now() {
+  unsafe { sys::vPortYield() }
+}
+
+/// Configuration for spawning a task
+#[derive(Debug, Clone)]
+pub struct TaskBuilder {
+  name: [u8; MAX_NAME_LEN],
+  stack_size: u32,
+  priority: u32,
+  core: Option<Core>,
+}
+
+impl TaskBuilder {
+  /// Create a builder with the default stack size and priority, for an unnamed task that may run
+  /// on either core
+  pub fn new() -> Self {
+    TaskBuilder {
+      name: [0; MAX_NAME_LEN],
+      stack_size: DEFAULT_STACK_SIZE,
+      priority: DEFAULT_PRIORITY,
+      core: None,
+    }
+  }
+
+  /// Name of the task, truncated to 15 bytes
+  pub fn name(mut self, name: &str) -> Self {
+    let mut len = name.len().min(MAX_NAME_LEN - 1);
+    while !name.is_char_boundary(len) {
+      len -= 1;
+    }
+
+    self.name = [0; MAX_NAME_LEN];
+    self.name[..len].copy_from_slice(&name.as_bytes()[..len]);
+    self
+  }
+
+  /// Stack size in bytes
+  ///
+  /// Must be at least [MIN_STACK_SIZE], plus the size of the closure and its result.
+  pub fn stack_size(mut self, bytes: u32) -> Self {
+    self.stack_size = bytes;
+    self
+  }
+
+  /// Priority, higher values are scheduled first. The idle tasks run at priority 0.
+  ///
+  /// Must be lower than [MAX_PRIORITIES].
+  pub fn priority(mut self, priority: u32) -> Self {
+    self.priority = priority;
+    self
+  }
+
+  /// Pin the task to a core
+  ///
+  /// [Core::App] is not available with `CONFIG_FREERTOS_UNICORE`.
+  pub fn core(mut self, core: Core) -> Self {
+    self.core = Some(core);
+    self
+  }
+
+  /// Spawn a task running `f`
+  ///
+  /// Returns [EspError::InvalidArg] if the stack is too small, the priority is too high or the
+  /// core does not exist, and [EspError::NoMem] if the task could not be allocated.
+  pub fn spawn<F, T>(self, f: F) -> Result<JoinHandle<T>, EspError>
+  where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+  {
+    // the closure is moved onto the task's stack, and its result is returned there
+    let min_stack_size = MIN_STACK_SIZE as usize + mem::size_of::<F>() + mem::size_of::<T>();
+    if (self.stack_size as usize) < min_stack_size || self.priority >= MAX_PRIORITIES {
+      return Err(EspError::InvalidArg);
+    }
+
+    let core = match self.core {
+      Some(core) if core.id() >= sys::portNUM_PROCESSORS as u32 => {
+        return Err(EspError::InvalidArg)
+      }
+      Some(core) => core.id() as sys::BaseType_t,
+      None => sys::tskNO_AFFINITY,
+    };
+
+    let done = unsafe {
+      sys::xQueueGenericCreate(
+        1,
+        sys::semSEMAPHORE_QUEUE_ITEM_LENGTH,
+        sys::queueQUEUE_TYPE_BINARY_SEMAPHORE,
+      )
+    };
+    if done.is_null() {
+      return Err(EspError::NoMem);
+    }
+
+    let packet = Packet {
+      header: Header::<T> {
+        refs: AtomicU8::new(2),
+        done,
+        result: UnsafeCell::new(None),
+      },
+      f: UnsafeCell::new(Some(f)),
+    };
+    let packet = match CapsBox::new_in(packet, HeapCaps::INTERNAL) {
+      Ok(packet) => CapsBox::into_raw(packet),
+      Err(e) => {
+        unsafe { sys::vQueueDelete(done) };
+        return Err(e);
+      }
+    };
+
+    let mut handle = ptr::null_mut();
+    let created = unsafe {
+      sys::xTaskCreatePinnedToCore(
+        Some(run::<F, T>),
+        self.name.as_ptr() as *const _,
+        self.stack_size,
+        packet as *mut c_void,
+        self.priority,
+        &mut handle,
+        core,
+      )
+    };
+
+    if created != sys::pdPASS {
+      unsafe {
+        sys::vQueueDelete(done);
+        drop(CapsBox::from_raw(packet));
+      }
+      return Err(EspError::NoMem);
+    }
+
+    Ok(JoinHandle {
+      header: packet as *const Header<T>,
+      release: release::<F, T>,
+      task: Task(handle),
+      _marker: PhantomData,
+    })
+  }
+}
+
+impl Default for TaskBuilder {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+struct Header<T> {
+  // the spawned task and the JoinHandle each own a reference
+  refs: AtomicU8,
+  done: sys::QueueHandle_t,
+  result: UnsafeCell<Option<T>>,
+}
+
+#[repr(C)]
+struct Packet<F, T> {
+  // first field, so the JoinHandle can reach it without knowing F
+  header: Header<T>,
+  f: UnsafeCell<Option<F>>,
+}
+
+unsafe extern "C" fn run<F, T>(arg: *mut c_void)
+where
+  F: FnOnce() -> T + Send + 'static,
+  T: Send + 'static,
+{
+  let packet = &*(arg as *const Packet<F, T>);
+  let f = (*packet.f.get()).take().unwrap();
+  *packet.header.result.get() = Some(f());
+  sys::xQueueGenericSend(
+    packet.header.done,
+    ptr::null(),
+    sys::semGIVE_BLOCK_TIME,
+    sys::queueSEND_TO_BACK,
+  );
+
+  release::<F, T>(arg);
+  sys::vTaskDelete(ptr::null_mut());
+}
+
+unsafe fn release<F, T>(packet: *mut c_void) {
+  let packet = packet as *mut Packet<F, T>;
+  if (*packet).header.refs.fetch_sub(1, Ordering::AcqRel) == 1 {
+    sys::vQueueDelete((*packet).header.done);
+    drop(CapsBox::from_raw(packet));
+  }
+}
+
+/// Owned permission to join a spawned task
+///
+/// Dropping the handle detaches the task, which keeps running.
+pub struct JoinHandle<T> {
+  header: *const Header<T>,
+  release: unsafe fn(*mut c_void),
+  task: Task,
+  _marker: PhantomData<T>,
+}
+
+unsafe impl<T: Send> Send for JoinHandle<T> {}
+unsafe impl<T: Send> Sync for JoinHandle<T> {}
+
+impl<T> JoinHandle<T> {
+  /// Handle of the spawned task
+  ///
+  /// The task deletes itself shortly after [JoinHandle::is_finished] returns `true` or
+  /// [JoinHandle::join] returns, so the handle must not be used after that. See [Task] for what
+  /// that means for the handle.
+  pub fn task(&self) -> Task {
+    self.task
+  }
+
+  /// Returns `true` if the task has finished running its closure
+  pub fn is_finished(&self) -> bool {
+    // the task drops its reference when it is done
+    unsafe { (*self.header).refs.load(Ordering::Acquire) == 1 }
+  }
+
+  /// Wait for the task to finish and return the result of its closure
+  pub fn join(self) -> T {
+    unsafe {
+      sys::xQueueSemaphoreTake((*self.header).done, sys::portMAX_DELAY);
+      (*(*self.header).result.get()).take().unwrap()
+    }
+  }
+}
+
+impl<T> Drop for JoinHandle<T> {
+  fn drop(&mut self) {
+    unsafe { (self.release)(self.header as *mut c_void) }
+  }
+}
+
+impl<T> fmt::Debug for JoinHandle<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("JoinHandle")
+      .field("task", &self.task)
+      .finish()
+  }
+}
@@ -0,0 +1,256 @@
+use core::{
+  cell::UnsafeCell,
+  ffi::c_void,
+  mem, ptr,
+  sync::atomic::{AtomicBool, AtomicPtr, Ordering},
+};
+
+use cty::{c_char, c_int};
+use esp_idf_freertos::PortMux;
+use esp_idf_log_sys as sys;
+use esp_idf_system::EspError;
+
+/// Longest line kept by [LogCapture], longer lines are truncated
+pub const CAPTURE_LINE_LEN: usize = 256;
+
+/// Replace the function IDF uses to write log output, returning the previous one
+///
+/// The function receives the output of every `ESP_LOGx` macro and of the Rust logger. Prefer
+/// [LogCapture] to keep the output in memory.
+///
+/// When this replaces the hook of a [LogCapture], `func` should pass output on to the returned
+/// function. The hook then stays chained after the capture is stopped, and keeps forwarding
+/// output to the function that was installed before the capture.
+pub fn set_vprintf(func: sys::vprintf_like_t) -> sys::vprintf_like_t {
+  unsafe { sys::esp_log_set_vprintf(func) }
+}
+
+struct Ring {
+  buffer: &'static mut [u8],
+  // index of the oldest byte
+  start: usize,
+  len: usize,
+  overwritten: usize,
+  echo: bool,
+}
+
+impl Ring {
+  fn push(&mut self, bytes: &[u8]) {
+    let cap = self.buffer.len();
+    // only the tail fits if the line is longer than the buffer
+    let skipped = bytes.len().saturating_sub(cap);
+    let bytes = &bytes[skipped..];
+
+    let overflow = (self.len + bytes.len()).saturating_sub(cap);
+    self.start = (self.start + overflow) % cap;
+    self.len -= overflow;
+    self.overwritten += overflow + skipped;
+
+    let mut end = (self.start + self.len) % cap;
+    for byte in bytes {
+      self.buffer[end] = *byte;
+      end = (end + 1) % cap;
+    }
+    self.len += bytes.len();
+  }
+
+  fn read(&mut self, out: &mut [u8]) -> usize {
+    let n = out.len().min(self.len);
+    let cap = self.buffer.len();
+    for (i, byte) in out[..n].iter_mut().enumerate() {
+      *byte = self.buffer[(self.start + i) % cap];
+    }
+
+    self.start = (self.start + n) % cap;
+    self.len -= n;
+    n
+  }
+}
+
+struct Shared(UnsafeCell<Option<Ring>>);
+
+// only accessed while holding MUX
+unsafe impl Sync for Shared {}
+
+static MUX: PortMux = PortMux::new();
+static RING: Shared = Shared(UnsafeCell::new(None));
+static IN_USE: AtomicBool = AtomicBool::new(false);
+// whether capture_vprintf is part of the output chain, it may stay there after a capture stops
+static HOOKED: AtomicBool = AtomicBool::new(false);
+// the output function capture_vprintf replaced, NULL if there was none
+static PREVIOUS: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+
+type VprintfFn = unsafe extern "C" fn(*const c_char, sys::va_list) -> c_int;
+
+fn previous() -> sys::vprintf_like_t {
+  let previous = PREVIOUS.load(Ordering::Acquire);
+  if previous.is_null() {
+    None
+  } else {
+    Some(unsafe { mem::transmute::<*mut c_void, VprintfFn>(previous) })
+  }
+}
+
+fn is_hook(func: sys::vprintf_like_t) -> bool {
+  func.map(|f| f as usize) == Some(capture_vprintf as VprintfFn as usize)
+}
+
+fn with_ring<R>(f: impl FnOnce(&mut Option<Ring>) -> R) -> R {
+  MUX.with(|| f(unsafe { &mut *RING.0.get() }))
+}
+
+unsafe extern "C" fn capture_vprintf(format: *const c_char, args: sys::va_list) -> c_int {
+  let mut line = [0u8; CAPTURE_LINE_LEN];
+  // `va_list` is a plain struct on xtensa, so a copy can be used to format the line twice
+  let written = sys::vsnprintf(line.as_mut_ptr() as *mut c_char, line.len(), format, args);
+  if written < 0 {
+    return written;
+  }
+  // vsnprintf returns the untruncated length, and always writes a terminating NUL
+  let len = (written as usize).min(line.len() - 1);
+
+  // without an active capture, output is only passed on
+  let echo = with_ring(|ring| match ring {
+    Some(ring) => {
+      ring.push(&line[..len]);
+      ring.echo
+    }
+    None => true,
+  });
+
+  match previous() {
+    Some(previous) if echo => previous(format, args),
+    _ => written,
+  }
+}
+
+/// Captures all log output into a ring buffer, for instance to upload it later
+///
+/// Once the buffer is full, the oldest output is overwritten. Lines longer than
+/// [CAPTURE_LINE_LEN] are truncated.
+///
+/// ```ignore
+/// static mut LOG_BUFFER: [u8; 8 * 1024] = [0; 8 * 1024];
+///
+/// let capture = LogCapture::start(unsafe { &mut LOG_BUFFER }, true)?;
+/// // later
+/// let mut chunk = [0; 512];
+/// loop {
+///   let n = capture.read(&mut chunk);
+///   if n == 0 {
+///     break;
+///   }
+///   upload(&chunk[..n])?;
+/// }
+/// ```
+///
+/// Only one capture can be active at a time.
+///
+/// Every line is formatted into a [CAPTURE_LINE_LEN] byte buffer on the stack of the logging
+/// task, so tasks that log while capturing need that much more stack.
+#[must_use = "capturing is stopped when the guard is dropped"]
+pub struct LogCapture {
+  _private: (),
+}
+
+impl LogCapture {
+  /// Start capturing log output into `buffer`
+  ///
+  /// If `echo` is `true`, output is also passed on to the previous output function, by default
+  /// the UART. Returns [EspError::InvalidState] if a capture is already active, and
+  /// [EspError::InvalidArg] if the buffer is empty.
+  pub fn start(buffer: &'static mut [u8], echo: bool) -> Result<Self, EspError> {
+    if buffer.is_empty() {
+      return Err(EspError::InvalidArg);
+    }
+
+    if IN_USE.swap(true, Ordering::Acquire) {
+      return Err(EspError::InvalidState);
+    }
+
+    with_ring(move |ring| {
+      *ring = Some(Ring {
+        buffer,
+        start: 0,
+        len: 0,
+        overwritten: 0,
+        echo,
+      })
+    });
+
+    // a hook left chained by an earlier capture already receives all output. Otherwise the
+    // previous function is stored after installing the hook, output in between is captured but
+    // not echoed.
+    if !HOOKED.swap(true, Ordering::AcqRel) {
+      let previous = set_vprintf(Some(capture_vprintf));
+      PREVIOUS.store(
+        previous.map_or(ptr::null_mut(), |f| f as *mut c_void),
+        Ordering::Release,
+      );
+    }
+
+    Ok(LogCapture { _private: () })
+  }
+
+  /// Number of captured bytes that have not been read
+  pub fn len(&self) -> usize {
+    with_ring(|ring| ring.as_ref().map_or(0, |ring| ring.len))
+  }
+
+  /// Returns `true` if there is no unread output
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// Number of bytes lost because the buffer was full or lines were too long
+  pub fn overwritten(&self) -> usize {
+    with_ring(|ring| ring.as_ref().map_or(0, |ring| ring.overwritten))
+  }
+
+  /// Move the oldest unread output into `out`, returns the number of bytes read
+  pub fn read(&self, out: &mut [u8]) -> usize {
+    with_ring(|ring| ring.as_mut().map_or(0, |ring| ring.read(out)))
+  }
+
+  /// Discard all unread output
+  pub fn clear(&self) {
+    with_ring(|ring| {
+      if let Some(ring) = ring {
+        ring.start = 0;
+        ring.len = 0;
+      }
+    })
+  }
+
+  /// Stop capturing and restore the previous output function
+  ///
+  /// The previous function is only restored if the capture is still the installed output
+  /// function. Otherwise the capture stays chained and passes all output on, see [set_vprintf].
+  ///
+  /// Returns the buffer, so it can be used for another capture.
+  pub fn stop(self) -> &'static mut [u8] {
+    let ring = stop();
+    core::mem::forget(self);
+    ring.buffer
+  }
+}
+
+impl Drop for LogCapture {
+  fn drop(&mut self) {
+    stop();
+  }
+}
+
+fn stop() -> Ring {
+  let ring = with_ring(|ring| ring.take()).unwrap();
+  // if the output function was replaced after the capture started, keep the replacement
+  let current = set_vprintf(previous());
+  if is_hook(current) {
+    PREVIOUS.store(ptr::null_mut(), Ordering::Release);
+    HOOKED.store(false, Ordering::Release);
+  } else {
+    set_vprintf(current);
+  }
+  IN_USE.store(false, Ordering::Release);
+  ring
+}
@@ -0,0 +1,34 @@
+use esp_idf_log_sys as sys;
+use log::LevelFilter;
+
+use crate::{level_to_raw, tag};
+
+/// Set the log level of a tag, for both Rust targets and C components
+///
+/// `log::max_level` is raised if needed, so Rust records at `level` are not filtered out
+/// before reaching IDF. C components can not log above the level they were compiled with,
+/// `CONFIG_LOG_DEFAULT_LEVEL` unless they define `LOG_LOCAL_LEVEL`.
+///
+/// ```ignore
+/// esp_idf_log::set_level("wifi", LevelFilter::Debug);
+/// esp_idf_log::set_level("my_app::sensor", LevelFilter::Off);
+/// ```
+pub fn set_level(tag: &str, level: LevelFilter) {
+  if level > log::max_level() {
+    log::set_max_level(level);
+  }
+
+  let level = level_to_raw(level);
+  unsafe { sys::esp_log_level_set(tag::set_level(tag, level), level) }
+}
+
+/// Set the log level of all tags
+///
+/// Like `esp_log_level_set("*", level)`, this also removes the levels set for individual tags
+/// with [set_level].
+pub fn set_default_level(level: LevelFilter) {
+  log::set_max_level(level);
+  let level = level_to_raw(level);
+  tag::set_default_level(level);
+  unsafe { sys::esp_log_level_set(b"*\0".as_ptr() as *const _, level) }
+}
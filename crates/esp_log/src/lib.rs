@@ -0,0 +1,28 @@
+//! `log` backend writing to the ESP-IDF log output.
+//!
+//! Records are written with `esp_log_write`, using the record's target as the IDF tag, so they
+//! are formatted and filtered like the logs of the C components:
+//!
+//! ```text
+//! I (1234) my_app::sensor: temperature 21.5 C
+//! ```
+//!
+//! ```ignore
+//! esp_idf_log::init().unwrap();
+//! log::info!("starting");
+//! ```
+//!
+//! Levels can be changed per tag at runtime with [set_level], which also applies to the C
+//! components. All log output can be captured into a ring buffer with [LogCapture].
+
+#![no_std]
+
+mod capture;
+mod level;
+mod logger;
+mod tag;
+
+pub use capture::*;
+pub use level::*;
+pub use log::{Level, LevelFilter};
+pub use logger::*;
@@ -0,0 +1,177 @@
+use core::fmt::{self, Write};
+
+use cty::{c_char, c_int};
+use esp_idf_log_sys as sys;
+use log::{Level, LevelFilter, Log, Metadata, Record, SetLoggerError};
+
+use crate::tag;
+
+/// Convert a `log` level to the IDF log level
+pub fn level_to_raw(level: LevelFilter) -> sys::esp_log_level_t {
+  match level {
+    LevelFilter::Off => sys::esp_log_level_t_ESP_LOG_NONE,
+    LevelFilter::Error => sys::esp_log_level_t_ESP_LOG_ERROR,
+    LevelFilter::Warn => sys::esp_log_level_t_ESP_LOG_WARN,
+    LevelFilter::Info => sys::esp_log_level_t_ESP_LOG_INFO,
+    LevelFilter::Debug => sys::esp_log_level_t_ESP_LOG_DEBUG,
+    LevelFilter::Trace => sys::esp_log_level_t_ESP_LOG_VERBOSE,
+  }
+}
+
+/// Convert an IDF log level to the `log` level
+pub fn level_from_raw(raw: sys::esp_log_level_t) -> LevelFilter {
+  match raw {
+    sys::esp_log_level_t_ESP_LOG_NONE => LevelFilter::Off,
+    sys::esp_log_level_t_ESP_LOG_ERROR => LevelFilter::Error,
+    sys::esp_log_level_t_ESP_LOG_WARN => LevelFilter::Warn,
+    sys::esp_log_level_t_ESP_LOG_INFO => LevelFilter::Info,
+    sys::esp_log_level_t_ESP_LOG_DEBUG => LevelFilter::Debug,
+    _ => LevelFilter::Trace,
+  }
+}
+
+/// The default log level, `CONFIG_LOG_DEFAULT_LEVEL` in sdkconfig
+pub fn default_level() -> LevelFilter {
+  level_from_raw(sys::CONFIG_LOG_DEFAULT_LEVEL)
+}
+
+/// Install the [EspLogger] as the `log` backend
+///
+/// The maximum level is set to [default_level]. Fails if another logger has already been
+/// installed.
+pub fn init() -> Result<(), SetLoggerError> {
+  static LOGGER: EspLogger = EspLogger::new();
+  LOGGER.install()
+}
+
+/// A `log` backend writing to the IDF log output
+///
+/// Lines are formatted like the `ESP_LOGx` macros do, using the record's target as tag. Output
+/// is filtered both by `log::max_level` and the IDF level of the tag.
+#[derive(Debug, Clone, Copy)]
+pub struct EspLogger {
+  colors: bool,
+}
+
+impl EspLogger {
+  /// Create a logger, with colors if `CONFIG_LOG_COLORS` is set
+  pub const fn new() -> Self {
+    EspLogger {
+      colors: sys::CONFIG_LOG_COLORS != 0,
+    }
+  }
+
+  /// Enable or disable colored output
+  pub const fn with_colors(self, colors: bool) -> Self {
+    EspLogger { colors }
+  }
+
+  /// Install the logger as the `log` backend, see [init]
+  pub fn install(&'static self) -> Result<(), SetLoggerError> {
+    log::set_logger(self)?;
+    log::set_max_level(default_level());
+    Ok(())
+  }
+}
+
+impl Default for EspLogger {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl Log for EspLogger {
+  // Levels set with `esp_log_level_set` from C are not seen here, esp_log_write still applies
+  // them.
+  fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+    metadata.level() <= log::max_level()
+      && metadata.level() <= level_from_raw(tag::level(metadata.target()))
+  }
+
+  fn log(&self, record: &Record<'_>) {
+    if !self.enabled(record.metadata()) {
+      return;
+    }
+
+    let (letter, color) = match record.level() {
+      Level::Error => ('E', sys::LOG_COLOR_E),
+      Level::Warn => ('W', sys::LOG_COLOR_W),
+      Level::Info => ('I', sys::LOG_COLOR_I),
+      Level::Debug => ('D', sys::LOG_COLOR_D),
+      Level::Trace => ('V', sys::LOG_COLOR_V),
+    };
+    let (color, reset) = if self.colors {
+      (color, sys::LOG_RESET_COLOR)
+    } else {
+      ("", "")
+    };
+
+    let mut writer = LineWriter {
+      level: level_to_raw(record.level().to_level_filter()),
+      tag: tag::intern(record.target()),
+      buf: [0; LINE_BUFFER_LEN],
+      len: 0,
+    };
+
+    // same format as LOG_FORMAT in esp_log.h
+    let _ = writeln!(
+      writer,
+      "{}{} ({}) {}: {}{}",
+      color,
+      letter,
+      unsafe { sys::esp_log_timestamp() },
+      record.target(),
+      record.args(),
+      reset
+    );
+    writer.flush();
+  }
+
+  fn flush(&self) {}
+}
+
+const LINE_BUFFER_LEN: usize = 128;
+
+// Collects output so most lines are written in a single esp_log_write call, which keeps lines
+// from different tasks from being interleaved.
+struct LineWriter {
+  level: sys::esp_log_level_t,
+  tag: *const c_char,
+  buf: [u8; LINE_BUFFER_LEN],
+  len: usize,
+}
+
+impl LineWriter {
+  fn flush(&mut self) {
+    if self.len > 0 {
+      unsafe {
+        sys::esp_log_write(
+          self.level,
+          self.tag,
+          b"%.*s\0".as_ptr() as *const c_char,
+          self.len as c_int,
+          self.buf.as_ptr(),
+        )
+      };
+      self.len = 0;
+    }
+  }
+}
+
+impl Write for LineWriter {
+  fn write_str(&mut self, s: &str) -> fmt::Result {
+    let mut bytes = s.as_bytes();
+    while !bytes.is_empty() {
+      if self.len == self.buf.len() {
+        self.flush();
+      }
+
+      let n = bytes.len().min(self.buf.len() - self.len);
+      self.buf[self.len..self.len + n].copy_from_slice(&bytes[..n]);
+      self.len += n;
+      bytes = &bytes[n..];
+    }
+
+    Ok(())
+  }
+}
@@ -0,0 +1,127 @@
+use core::{
+  ptr,
+  sync::atomic::{AtomicPtr, AtomicU32, Ordering},
+};
+
+use cty::c_char;
+use esp_idf_log_sys as sys;
+use esp_idf_system::heap::{CapsBox, CapsVec, HeapCaps};
+
+// IDF caches tag levels by pointer, so every tag passed to it needs a stable address. Tags are
+// interned in a list that is never freed, there is only a bounded number of log targets.
+//
+// IDF 4.2 has no way to read back the level of a tag, so the levels set through this crate are
+// mirrored in the list as well.
+struct Tag {
+  next: *const Tag,
+  // NUL-terminated
+  name: CapsVec<u8>,
+  // UNSET if the tag uses the default level
+  level: AtomicU32,
+}
+
+impl Tag {
+  fn name(&self) -> *const c_char {
+    self.name.as_ptr() as *const c_char
+  }
+}
+
+const UNSET: u32 = u32::MAX;
+
+static TAGS: AtomicPtr<Tag> = AtomicPtr::new(ptr::null_mut());
+static DEFAULT_LEVEL: AtomicU32 = AtomicU32::new(sys::CONFIG_LOG_DEFAULT_LEVEL);
+
+// used when the tag could not be allocated, it gets the default level
+const FALLBACK: &[u8] = b"\0";
+
+fn find(mut node: *const Tag, name: &str) -> Option<&'static Tag> {
+  while !node.is_null() {
+    let tag = unsafe { &*node };
+    if &tag.name[..tag.name.len() - 1] == name.as_bytes() {
+      return Some(tag);
+    }
+
+    node = tag.next;
+  }
+
+  None
+}
+
+/// The IDF level of a tag, as set by [set_level] and [set_default_level]
+pub(crate) fn level(name: &str) -> sys::esp_log_level_t {
+  let level =
+    find(TAGS.load(Ordering::Acquire), name).map_or(UNSET, |tag| tag.level.load(Ordering::Relaxed));
+  if level == UNSET {
+    DEFAULT_LEVEL.load(Ordering::Relaxed)
+  } else {
+    level
+  }
+}
+
+/// Record the level of a tag, returns the interned tag
+pub(crate) fn set_level(name: &str, level: sys::esp_log_level_t) -> *const c_char {
+  match insert(name) {
+    Some(tag) => {
+      tag.level.store(level, Ordering::Relaxed);
+      tag.name()
+    }
+    None => FALLBACK.as_ptr() as *const c_char,
+  }
+}
+
+/// Record the level of all tags, this resets the levels of individual tags
+pub(crate) fn set_default_level(level: sys::esp_log_level_t) {
+  DEFAULT_LEVEL.store(level, Ordering::Relaxed);
+  let mut node = TAGS.load(Ordering::Acquire) as *const Tag;
+  while !node.is_null() {
+    let tag = unsafe { &*node };
+    tag.level.store(UNSET, Ordering::Relaxed);
+    node = tag.next;
+  }
+}
+
+/// Get a NUL-terminated copy of `name` with a stable address
+pub(crate) fn intern(name: &str) -> *const c_char {
+  insert(name).map_or(FALLBACK.as_ptr() as *const c_char, Tag::name)
+}
+
+fn insert(name: &str) -> Option<&'static Tag> {
+  let mut head = TAGS.load(Ordering::Acquire);
+  if let Some(tag) = find(head, name) {
+    return Some(tag);
+  }
+
+  let node = CapsVec::from_slice(name.as_bytes(), HeapCaps::DEFAULT)
+    .and_then(|mut tag| tag.push(0).map(|()| tag))
+    .and_then(|tag| {
+      CapsBox::new_in(
+        Tag {
+          next: ptr::null(),
+          name: tag,
+          level: AtomicU32::new(UNSET),
+        },
+        HeapCaps::DEFAULT,
+      )
+    });
+
+  let node = match node {
+    Ok(node) => CapsBox::into_raw(node),
+    Err(_) => return None,
+  };
+
+  loop {
+    unsafe { (*node).next = head };
+    match TAGS.compare_exchange_weak(head, node, Ordering::AcqRel, Ordering::Acquire) {
+      Ok(_) => return Some(unsafe { &*node }),
+      Err(current) => {
+        // another task added tags in the meantime, it might have been this one
+        if let Some(tag) = find(current, name) {
+          drop(unsafe { CapsBox::from_raw(node) });
+          return Some(tag);
+        }
+
+        head = current;
+      }
+    }
+  }
+}
@@ -0,0 +1,139 @@
+use core::ptr;
+
+use esp_idf_nvs_flash_sys as sys;
+use esp_idf_system::EspError;
+
+use crate::{key_name, name_str, partition_name, ValueType};
+
+/// Information about an NVS entry, see [entries]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct EntryInfo {
+  namespace: [u8; sys::NVS_KEY_NAME_MAX_SIZE],
+  key: [u8; sys::NVS_KEY_NAME_MAX_SIZE],
+  value_type: ValueType,
+}
+
+impl EntryInfo {
+  fn from_raw(raw: &sys::nvs_entry_info_t) -> Self {
+    let mut info = EntryInfo {
+      namespace: [0; sys::NVS_KEY_NAME_MAX_SIZE],
+      key: [0; sys::NVS_KEY_NAME_MAX_SIZE],
+      value_type: ValueType::from_raw(raw.type_),
+    };
+
+    for (byte, raw) in info.namespace.iter_mut().zip(raw.namespace_name.iter()) {
+      *byte = *raw as u8;
+    }
+    for (byte, raw) in info.key.iter_mut().zip(raw.key.iter()) {
+      *byte = *raw as u8;
+    }
+
+    info
+  }
+
+  /// Namespace the entry belongs to
+  pub fn namespace(&self) -> &str {
+    name_str(&self.namespace)
+  }
+
+  /// Key of the entry
+  pub fn key(&self) -> &str {
+    name_str(&self.key)
+  }
+
+  /// Type of the stored value
+  pub fn value_type(&self) -> ValueType {
+    self.value_type
+  }
+}
+
+impl core::fmt::Debug for EntryInfo {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    f.debug_struct("EntryInfo")
+      .field("namespace", &self.namespace())
+      .field("key", &self.key())
+      .field("value_type", &self.value_type)
+      .finish()
+  }
+}
+
+/// Iterate over the entries in an initialized partition
+///
+/// Entries can be limited to a single namespace and value type.
+///
+/// ```ignore
+/// for entry in esp_idf_nvs::entries(DEFAULT_PARTITION, Some("settings"), None)? {
+///   println!("{} ({:?})", entry.key(), entry.value_type());
+/// }
+/// ```
+pub fn entries(
+  partition: &str,
+  namespace: Option<&str>,
+  value_type: Option<ValueType>,
+) -> Result<Entries, EspError> {
+  let partition = partition_name(partition)?;
+  let namespace = match namespace {
+    Some(namespace) => Some(key_name(namespace)?),
+    None => None,
+  };
+
+  Ok(find(
+    &partition,
+    namespace.as_ref().map(|n| &n[..]),
+    value_type,
+  ))
+}
+
+pub(crate) fn find(
+  partition: &[u8],
+  namespace: Option<&[u8]>,
+  value_type: Option<ValueType>,
+) -> Entries {
+  let namespace = namespace.map_or(ptr::null(), |namespace| namespace.as_ptr());
+  let value_type = value_type.map_or(sys::nvs_type_t_NVS_TYPE_ANY, ValueType::to_raw);
+
+  Entries {
+    iter: unsafe {
+      sys::nvs_entry_find(
+        partition.as_ptr() as *const _,
+        namespace as *const _,
+        value_type,
+      )
+    },
+  }
+}
+
+/// Iterator over NVS entries, see [entries]
+pub struct Entries {
+  // NULL once exhausted
+  iter: sys::nvs_iterator_t,
+}
+
+impl Iterator for Entries {
+  type Item = EntryInfo;
+
+  fn next(&mut self) -> Option<EntryInfo> {
+    if self.iter.is_null() {
+      return None;
+    }
+
+    let mut raw = sys::nvs_entry_info_t {
+      namespace_name: [0; 16],
+      key: [0; 16],
+      type_: sys::nvs_type_t_NVS_TYPE_ANY,
+    };
+    unsafe {
+      sys::nvs_entry_info(self.iter, &mut raw);
+      // releases the iterator when there are no more entries
+      self.iter = sys::nvs_entry_next(self.iter);
+    }
+
+    Some(EntryInfo::from_raw(&raw))
+  }
+}
+
+impl Drop for Entries {
+  fn drop(&mut self) {
+    unsafe { sys::nvs_release_iterator(self.iter) }
+  }
+}
@@ -0,0 +1,118 @@
+//! Non-volatile storage (NVS), a key/value store in flash.
+//!
+//! ```ignore
+//! esp_idf_nvs::init()?;
+//!
+//! let settings = Namespace::open(DEFAULT_PARTITION, "settings", OpenMode::ReadWrite)?;
+//! let boots = settings.get::<u32>("boots")?.unwrap_or(0);
+//! settings.set("boots", boots + 1)?;
+//! settings.commit()?;
+//!
+//! let mut buf = [0; 64];
+//! if let Some(ssid) = settings.get_str("ssid", &mut buf)? {
+//!   connect(ssid);
+//! }
+//! ```
+
+#![no_std]
+
+use esp_idf_nvs_flash_sys as sys;
+use esp_idf_system::{esp_result, EspError};
+
+mod entries;
+mod namespace;
+mod value;
+
+pub use entries::*;
+pub use namespace::*;
+pub use value::*;
+
+/// Label of the default NVS partition
+pub const DEFAULT_PARTITION: &str = sys::NVS_DEFAULT_PART_NAME;
+
+/// Maximum length of keys and namespace names, longer names are rejected with
+/// [EspError::InvalidArg]
+pub const MAX_KEY_LEN: usize = sys::NVS_KEY_NAME_MAX_SIZE - 1;
+
+/// Maximum length of partition labels, longer labels are rejected with [EspError::InvalidArg]
+pub const MAX_PARTITION_LEN: usize = sys::NVS_PART_NAME_MAX_SIZE;
+
+/// Initialize the default NVS partition
+///
+/// If the partition has no free pages or was written by a newer NVS version, it is erased and
+/// initialized again.
+pub fn init() -> Result<(), EspError> {
+  init_partition(DEFAULT_PARTITION)
+}
+
+/// Initialize an NVS partition, see [init]
+pub fn init_partition(partition: &str) -> Result<(), EspError> {
+  let partition = partition_name(partition)?;
+  let partition = partition.as_ptr() as *const _;
+
+  match esp_result(unsafe { sys::nvs_flash_init_partition(partition) }) {
+    Err(EspError::Other(sys::ESP_ERR_NVS_NO_FREE_PAGES))
+    | Err(EspError::Other(sys::ESP_ERR_NVS_NEW_VERSION_FOUND)) => {
+      esp_result(unsafe { sys::nvs_flash_erase_partition(partition) })?;
+      esp_result(unsafe { sys::nvs_flash_init_partition(partition) })
+    }
+    result => result,
+  }
+}
+
+/// Deinitialize an NVS partition
+///
+/// Returns `ESP_ERR_NVS_NOT_INITIALIZED` if the partition was not initialized.
+pub fn deinit_partition(partition: &str) -> Result<(), EspError> {
+  let partition = partition_name(partition)?;
+  esp_result(unsafe { sys::nvs_flash_deinit_partition(partition.as_ptr() as *const _) })
+}
+
+/// Erase all contents of an NVS partition
+///
+/// The partition is deinitialized first, and has to be initialized again to be used.
+pub fn erase_partition(partition: &str) -> Result<(), EspError> {
+  let partition = partition_name(partition)?;
+  esp_result(unsafe { sys::nvs_flash_erase_partition(partition.as_ptr() as *const _) })
+}
+
+fn copy_name(name: &str, buf: &mut [u8]) -> Result<(), EspError> {
+  // one byte is needed for the NUL terminator
+  if name.len() >= buf.len() || name.as_bytes().contains(&0) {
+    return Err(EspError::InvalidArg);
+  }
+
+  buf[..name.len()].copy_from_slice(name.as_bytes());
+  Ok(())
+}
+
+/// NUL-terminated copy of a partition label
+pub(crate) fn partition_name(
+  name: &str,
+) -> Result<[u8; sys::NVS_PART_NAME_MAX_SIZE + 1], EspError> {
+  let mut buf = [0; sys::NVS_PART_NAME_MAX_SIZE + 1];
+  copy_name(name, &mut buf)?;
+  Ok(buf)
+}
+
+/// NUL-terminated copy of a key or namespace name
+pub(crate) fn key_name(name: &str) -> Result<[u8; sys::NVS_KEY_NAME_MAX_SIZE], EspError> {
+  let mut buf = [0; sys::NVS_KEY_NAME_MAX_SIZE];
+  copy_name(name, &mut buf)?;
+  Ok(buf)
+}
+
+/// Map `ESP_ERR_NVS_NOT_FOUND` to `None`
+pub(crate) fn optional<T>(result: Result<T, EspError>) -> Result<Option<T>, EspError> {
+  match result {
+    Ok(value) => Ok(Some(value)),
+    Err(EspError::Other(sys::ESP_ERR_NVS_NOT_FOUND)) => Ok(None),
+    Err(e) => Err(e),
+  }
+}
+
+// names are stored NUL-terminated in fixed size buffers
+pub(crate) fn name_str(buf: &[u8]) -> &str {
+  let len = buf.iter().position(|b| *b == 0).unwrap_or(buf.len());
+  core::str::from_utf8(&buf[..len]).unwrap_or("")
+}
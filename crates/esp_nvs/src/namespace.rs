@@ -0,0 +1,256 @@
+use core::ptr;
+
+use esp_idf_nvs_flash_sys as sys;
+use esp_idf_system::{
+  esp_result,
+  heap::{CapsVec, HeapCaps},
+  EspError,
+};
+
+use crate::{
+  entries::find, key_name, name_str, optional, partition_name, Entries, IntValue, ValueType,
+};
+
+/// How a [Namespace] is opened
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OpenMode {
+  /// Only reading is allowed, writes fail with `ESP_ERR_NVS_READ_ONLY`
+  ReadOnly,
+  /// Reading and writing is allowed, the namespace is created if it does not exist
+  ReadWrite,
+}
+
+impl OpenMode {
+  fn to_raw(self) -> sys::nvs_open_mode_t {
+    match self {
+      Self::ReadOnly => sys::nvs_open_mode_t_NVS_READONLY,
+      Self::ReadWrite => sys::nvs_open_mode_t_NVS_READWRITE,
+    }
+  }
+}
+
+/// An open NVS namespace, closed when dropped
+///
+/// Getters return `Ok(None)` if the key does not exist, and `ESP_ERR_NVS_TYPE_MISMATCH` if it
+/// holds a value of another type. Changes are only guaranteed to be written to flash after
+/// [Namespace::commit].
+pub struct Namespace {
+  handle: sys::nvs_handle_t,
+  partition: [u8; sys::NVS_PART_NAME_MAX_SIZE + 1],
+  name: [u8; sys::NVS_KEY_NAME_MAX_SIZE],
+}
+
+impl Namespace {
+  /// Open the namespace `name` in an initialized partition
+  ///
+  /// Opening a namespace that does not exist with [OpenMode::ReadOnly] fails with
+  /// `ESP_ERR_NVS_NOT_FOUND`.
+  pub fn open(partition: &str, name: &str, mode: OpenMode) -> Result<Self, EspError> {
+    let partition = partition_name(partition)?;
+    let name = key_name(name)?;
+
+    let mut handle = 0;
+    esp_result(unsafe {
+      sys::nvs_open_from_partition(
+        partition.as_ptr() as *const _,
+        name.as_ptr() as *const _,
+        mode.to_raw(),
+        &mut handle,
+      )
+    })?;
+
+    Ok(Namespace {
+      handle,
+      partition,
+      name,
+    })
+  }
+
+  /// Label of the partition the namespace is in
+  pub fn partition(&self) -> &str {
+    name_str(&self.partition)
+  }
+
+  /// Name of the namespace
+  pub fn name(&self) -> &str {
+    name_str(&self.name)
+  }
+
+  /// Get an integer
+  pub fn get<T: IntValue>(&self, key: &str) -> Result<Option<T>, EspError> {
+    let key = key_name(key)?;
+    let mut value = T::default();
+    optional(
+      esp_result(unsafe { T::get_raw(self.handle, key.as_ptr() as *const _, &mut value) })
+        .map(|()| value),
+    )
+  }
+
+  /// Set an integer
+  pub fn set<T: IntValue>(&self, key: &str, value: T) -> Result<(), EspError> {
+    let key = key_name(key)?;
+    esp_result(unsafe { T::set_raw(self.handle, key.as_ptr() as *const _, value) })
+  }
+
+  /// Length of a string in bytes
+  pub fn str_len(&self, key: &str) -> Result<Option<usize>, EspError> {
+    let key = key_name(key)?;
+    let mut len = 0;
+    optional(
+      esp_result(unsafe {
+        sys::nvs_get_str(
+          self.handle,
+          key.as_ptr() as *const _,
+          ptr::null_mut(),
+          &mut len,
+        )
+      })
+      // without the NUL terminator
+      .map(|()| len.saturating_sub(1)),
+    )
+  }
+
+  /// Read a string into `buf`
+  ///
+  /// `buf` needs one byte more than [Namespace::str_len], otherwise `ESP_ERR_NVS_INVALID_LENGTH`
+  /// is returned. Strings that are not valid UTF-8 are reported as `ESP_ERR_NVS_TYPE_MISMATCH`.
+  pub fn get_str<'a>(&self, key: &str, buf: &'a mut [u8]) -> Result<Option<&'a str>, EspError> {
+    if buf.is_empty() {
+      return Err(EspError::Other(sys::ESP_ERR_NVS_INVALID_LENGTH));
+    }
+
+    let key = key_name(key)?;
+    let mut len = buf.len();
+    let found = optional(esp_result(unsafe {
+      sys::nvs_get_str(
+        self.handle,
+        key.as_ptr() as *const _,
+        buf.as_mut_ptr() as *mut _,
+        &mut len,
+      )
+    }))?;
+
+    match found {
+      Some(()) => core::str::from_utf8(&buf[..len.saturating_sub(1)])
+        .map(Some)
+        .map_err(|_| EspError::Other(sys::ESP_ERR_NVS_TYPE_MISMATCH)),
+      None => Ok(None),
+    }
+  }
+
+  /// Set a string
+  ///
+  /// Strings containing NUL bytes are rejected with [EspError::InvalidArg].
+  pub fn set_str(&self, key: &str, value: &str) -> Result<(), EspError> {
+    if value.as_bytes().contains(&0) {
+      return Err(EspError::InvalidArg);
+    }
+
+    let key = key_name(key)?;
+    // NVS takes a NUL-terminated string
+    let mut c_value = CapsVec::with_capacity(value.len() + 1, HeapCaps::DEFAULT)?;
+    c_value.extend_from_slice(value.as_bytes())?;
+    c_value.push(0)?;
+
+    esp_result(unsafe {
+      sys::nvs_set_str(
+        self.handle,
+        key.as_ptr() as *const _,
+        c_value.as_ptr() as *const _,
+      )
+    })
+  }
+
+  /// Length of a blob in bytes
+  pub fn blob_len(&self, key: &str) -> Result<Option<usize>, EspError> {
+    let key = key_name(key)?;
+    let mut len = 0;
+    optional(
+      esp_result(unsafe {
+        sys::nvs_get_blob(
+          self.handle,
+          key.as_ptr() as *const _,
+          ptr::null_mut(),
+          &mut len,
+        )
+      })
+      .map(|()| len),
+    )
+  }
+
+  /// Read a blob into `buf`
+  ///
+  /// `buf` needs to be at least [Namespace::blob_len] bytes long, otherwise
+  /// `ESP_ERR_NVS_INVALID_LENGTH` is returned.
+  pub fn get_blob<'a>(&self, key: &str, buf: &'a mut [u8]) -> Result<Option<&'a [u8]>, EspError> {
+    // NVS treats a NULL buffer as a length query
+    if buf.is_empty() {
+      return match self.blob_len(key)? {
+        Some(0) => Ok(Some(&buf[..])),
+        Some(_) => Err(EspError::Other(sys::ESP_ERR_NVS_INVALID_LENGTH)),
+        None => Ok(None),
+      };
+    }
+
+    let key = key_name(key)?;
+    let mut len = buf.len();
+    let found = optional(esp_result(unsafe {
+      sys::nvs_get_blob(
+        self.handle,
+        key.as_ptr() as *const _,
+        buf.as_mut_ptr() as *mut _,
+        &mut len,
+      )
+    }))?;
+
+    Ok(found.map(move |()| &buf[..len]))
+  }
+
+  /// Set a blob
+  pub fn set_blob(&self, key: &str, value: &[u8]) -> Result<(), EspError> {
+    let key = key_name(key)?;
+    esp_result(unsafe {
+      sys::nvs_set_blob(
+        self.handle,
+        key.as_ptr() as *const _,
+        value.as_ptr() as *const _,
+        value.len(),
+      )
+    })
+  }
+
+  /// Erase a key, returns `false` if it did not exist
+  pub fn erase_key(&self, key: &str) -> Result<bool, EspError> {
+    let key = key_name(key)?;
+    optional(esp_result(unsafe {
+      sys::nvs_erase_key(self.handle, key.as_ptr() as *const _)
+    }))
+    .map(|erased| erased.is_some())
+  }
+
+  /// Erase all keys in the namespace
+  pub fn erase_all(&self) -> Result<(), EspError> {
+    esp_result(unsafe { sys::nvs_erase_all(self.handle) })
+  }
+
+  /// Write pending changes to flash
+  pub fn commit(&self) -> Result<(), EspError> {
+    esp_result(unsafe { sys::nvs_commit(self.handle) })
+  }
+
+  /// Iterate over the entries in the namespace, optionally only those of type `value_type`
+  pub fn entries(&self, value_type: Option<ValueType>) -> Entries {
+    find(&self.partition, Some(&self.name), value_type)
+  }
+
+  /// The raw NVS handle
+  pub fn as_raw(&self) -> sys::nvs_handle_t {
+    self.handle
+  }
+}
+
+impl Drop for Namespace {
+  fn drop(&mut self) {
+    unsafe { sys::nvs_close(self.handle) }
+  }
+}
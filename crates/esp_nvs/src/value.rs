@@ -0,0 +1,116 @@
+use esp_idf_nvs_flash_sys as sys;
+
+/// Type of a value stored in NVS
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValueType {
+  U8,
+  I8,
+  U16,
+  I16,
+  U32,
+  I32,
+  U64,
+  I64,
+  Str,
+  Blob,
+  /// Others, with the raw `nvs_type_t` value
+  Unknown(u32),
+}
+
+impl ValueType {
+  pub(crate) fn from_raw(raw: sys::nvs_type_t) -> Self {
+    match raw {
+      sys::nvs_type_t_NVS_TYPE_U8 => Self::U8,
+      sys::nvs_type_t_NVS_TYPE_I8 => Self::I8,
+      sys::nvs_type_t_NVS_TYPE_U16 => Self::U16,
+      sys::nvs_type_t_NVS_TYPE_I16 => Self::I16,
+      sys::nvs_type_t_NVS_TYPE_U32 => Self::U32,
+      sys::nvs_type_t_NVS_TYPE_I32 => Self::I32,
+      sys::nvs_type_t_NVS_TYPE_U64 => Self::U64,
+      sys::nvs_type_t_NVS_TYPE_I64 => Self::I64,
+      sys::nvs_type_t_NVS_TYPE_STR => Self::Str,
+      sys::nvs_type_t_NVS_TYPE_BLOB => Self::Blob,
+      raw => Self::Unknown(raw),
+    }
+  }
+
+  pub(crate) fn to_raw(self) -> sys::nvs_type_t {
+    match self {
+      Self::U8 => sys::nvs_type_t_NVS_TYPE_U8,
+      Self::I8 => sys::nvs_type_t_NVS_TYPE_I8,
+      Self::U16 => sys::nvs_type_t_NVS_TYPE_U16,
+      Self::I16 => sys::nvs_type_t_NVS_TYPE_I16,
+      Self::U32 => sys::nvs_type_t_NVS_TYPE_U32,
+      Self::I32 => sys::nvs_type_t_NVS_TYPE_I32,
+      Self::U64 => sys::nvs_type_t_NVS_TYPE_U64,
+      Self::I64 => sys::nvs_type_t_NVS_TYPE_I64,
+      Self::Str => sys::nvs_type_t_NVS_TYPE_STR,
+      Self::Blob => sys::nvs_type_t_NVS_TYPE_BLOB,
+      Self::Unknown(raw) => raw,
+    }
+  }
+}
+
+mod sealed {
+  pub trait Sealed {}
+}
+
+/// Integer types that can be stored in NVS, see [Namespace::get](crate::Namespace::get) and
+/// [Namespace::set](crate::Namespace::set)
+pub trait IntValue: Copy + Default + sealed::Sealed {
+  /// The NVS type the integer is stored as
+  const TYPE: ValueType;
+
+  #[doc(hidden)]
+  unsafe fn get_raw(
+    handle: sys::nvs_handle_t,
+    key: *const cty::c_char,
+    out: *mut Self,
+  ) -> sys::esp_err_t;
+
+  #[doc(hidden)]
+  unsafe fn set_raw(
+    handle: sys::nvs_handle_t,
+    key: *const cty::c_char,
+    value: Self,
+  ) -> sys::esp_err_t;
+}
+
+macro_rules! int_value {
+  ($($ty:ty => $variant:ident, $get:ident, $set:ident;)*) => {
+    $(
+      impl sealed::Sealed for $ty {}
+
+      impl IntValue for $ty {
+        const TYPE: ValueType = ValueType::$variant;
+
+        unsafe fn get_raw(
+          handle: sys::nvs_handle_t,
+          key: *const cty::c_char,
+          out: *mut Self,
+        ) -> sys::esp_err_t {
+          sys::$get(handle, key, out)
+        }
+
+        unsafe fn set_raw(
+          handle: sys::nvs_handle_t,
+          key: *const cty::c_char,
+          value: Self,
+        ) -> sys::esp_err_t {
+          sys::$set(handle, key, value)
+        }
+      }
+    )*
+  };
+}
+
+int_value! {
+  u8 => U8, nvs_get_u8, nvs_set_u8;
+  i8 => I8, nvs_get_i8, nvs_set_i8;
+  u16 => U16, nvs_get_u16, nvs_set_u16;
+  i16 => I16, nvs_get_i16, nvs_set_i16;
+  u32 => U32, nvs_get_u32, nvs_set_u32;
+  i32 => I32, nvs_get_i32, nvs_set_i32;
+  u64 => U64, nvs_get_u64, nvs_set_u64;
+  i64 => I64, nvs_get_i64, nvs_set_i64;
+}
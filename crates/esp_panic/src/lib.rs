@@ -0,0 +1,55 @@
+//! Panic handler for ESP-IDF applications.
+//!
+//! Link this crate into the final binary to get a `#[panic_handler]`:
+//!
+//! ```ignore
+//! extern crate esp_idf_panic;
+//! ```
+//!
+//! One of the following features selects what happens on panic. Because `abort` is a default
+//! feature, enabling several is allowed, and `halt` takes precedence over `restart`, which takes
+//! precedence over `abort`:
+//!
+//! * `abort` (default) - the panic message and location are passed to `esp_system_abort`, so the
+//!   IDF panic handler prints them, dumps registers, optionally writes a core dump and reboots.
+//!   The next boot reports `EspResetReason::Panic`.
+//! * `restart` - restart immediately using `esp_restart`. The panic message is lost.
+//! * `halt` - spin forever. The panic message is lost.
+
+#![no_std]
+
+#[cfg(not(any(feature = "abort", feature = "restart", feature = "halt")))]
+compile_error!("one of the features `abort`, `restart` or `halt` must be enabled");
+
+use core::panic::PanicInfo;
+
+#[cfg(all(feature = "abort", not(feature = "restart"), not(feature = "halt")))]
+#[panic_handler]
+fn panic(info: &PanicInfo) -> ! {
+  use core::sync::atomic::{AtomicBool, Ordering};
+
+  static PANICKING: AtomicBool = AtomicBool::new(false);
+
+  // formatting the message may itself panic, don't recurse
+  if PANICKING.swap(true, Ordering::Relaxed) {
+    esp_idf_system::system_abort("panicked while processing panic");
+  }
+
+  esp_idf_system::system_abort_fmt(format_args!("{}", info))
+}
+
+#[cfg(all(feature = "restart", not(feature = "halt")))]
+#[panic_handler]
+fn panic(_info: &PanicInfo) -> ! {
+  esp_idf_system::restart()
+}
+
+#[cfg(feature = "halt")]
+#[panic_handler]
+fn panic(_info: &PanicInfo) -> ! {
+  use core::sync::atomic::{compiler_fence, Ordering};
+
+  loop {
+    compiler_fence(Ordering::SeqCst);
+  }
+}
@@ -0,0 +1,382 @@
+//! Light sleep and deep sleep.
+//!
+//! Wakeup sources are collected in a [SleepConfig], which checks that they can be used together
+//! before the chip goes to sleep:
+//!
+//! ```ignore
+//! SleepConfig::new()
+//!   .timer(Duration::from_secs(60))
+//!   .ext0(GPIO_BUTTON, Level::Low)
+//!   .deep_sleep()?;
+//! ```
+//!
+//! After waking up from deep sleep the application starts over, use [wakeup_cause] to find out
+//! why.
+
+#![no_std]
+
+use core::{
+  convert::{Infallible, TryFrom},
+  fmt,
+  hint::unreachable_unchecked,
+  sync::atomic::{AtomicU32, Ordering},
+  time::Duration,
+};
+
+use esp_idf_driver_sys as driver_sys;
+use esp_idf_sleep_sys as sys;
+use esp_idf_system::{esp_result, EspError};
+
+// GPIO0-39, except 20, 24 and 28-31
+const VALID_GPIO_MASK: u64 = 0xff_0eef_ffff;
+
+// GPIOs enabled with gpio_wakeup_enable by the last SleepConfig::apply, which are not cleared by
+// esp_sleep_disable_wakeup_source. The ESP32 has no 64-bit atomics, so the mask is split.
+static GPIO_WAKEUP_LOW: AtomicU32 = AtomicU32::new(0);
+static GPIO_WAKEUP_HIGH: AtomicU32 = AtomicU32::new(0);
+
+/// Store the mask of GPIOs enabled for wakeup, returning the previous mask
+fn swap_gpio_wakeup(mask: u64) -> u64 {
+  let low = GPIO_WAKEUP_LOW.swap(mask as u32, Ordering::Relaxed);
+  let high = GPIO_WAKEUP_HIGH.swap((mask >> 32) as u32, Ordering::Relaxed);
+  (high as u64) << 32 | low as u64
+}
+
+/// Sleep mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SleepMode {
+  /// CPUs are paused and RAM is retained, execution continues after waking up
+  Light,
+  /// Only the RTC domain stays powered, the chip restarts after waking up
+  Deep,
+}
+
+/// Input level
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Level {
+  /// Logic low
+  Low,
+  /// Logic high
+  High,
+}
+
+/// Logic function used for [SleepConfig::ext1]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Ext1Mode {
+  /// Wake up when all selected GPIOs are low
+  AllLow,
+  /// Wake up when any of the selected GPIOs is high
+  AnyHigh,
+}
+
+impl Ext1Mode {
+  fn to_raw(self) -> sys::esp_sleep_ext1_wakeup_mode_t {
+    match self {
+      Self::AllLow => sys::esp_sleep_ext1_wakeup_mode_t_ESP_EXT1_WAKEUP_ALL_LOW,
+      Self::AnyHigh => sys::esp_sleep_ext1_wakeup_mode_t_ESP_EXT1_WAKEUP_ANY_HIGH,
+    }
+  }
+}
+
+/// Source which caused the last wakeup from sleep
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WakeupCause {
+  /// Not woken up from sleep, e.g. after power-on
+  Undefined,
+  /// External signal using RTC_IO
+  Ext0,
+  /// External signal using RTC_CNTL, see [ext1_wakeup_status]
+  Ext1,
+  /// Timer
+  Timer,
+  /// Touchpad, see [touchpad_wakeup_status]
+  Touchpad,
+  /// ULP program
+  Ulp,
+  /// GPIO (light sleep only)
+  Gpio,
+  /// UART (light sleep only)
+  Uart,
+  /// Others, with the raw `esp_sleep_wakeup_cause_t` value
+  Unknown(u32),
+}
+
+impl WakeupCause {
+  fn from_raw(raw: sys::esp_sleep_wakeup_cause_t) -> Self {
+    match raw {
+      sys::esp_sleep_source_t_ESP_SLEEP_WAKEUP_UNDEFINED => Self::Undefined,
+      sys::esp_sleep_source_t_ESP_SLEEP_WAKEUP_EXT0 => Self::Ext0,
+      sys::esp_sleep_source_t_ESP_SLEEP_WAKEUP_EXT1 => Self::Ext1,
+      sys::esp_sleep_source_t_ESP_SLEEP_WAKEUP_TIMER => Self::Timer,
+      sys::esp_sleep_source_t_ESP_SLEEP_WAKEUP_TOUCHPAD => Self::Touchpad,
+      sys::esp_sleep_source_t_ESP_SLEEP_WAKEUP_ULP => Self::Ulp,
+      sys::esp_sleep_source_t_ESP_SLEEP_WAKEUP_GPIO => Self::Gpio,
+      sys::esp_sleep_source_t_ESP_SLEEP_WAKEUP_UART => Self::Uart,
+      raw => Self::Unknown(raw),
+    }
+  }
+}
+
+impl fmt::Display for WakeupCause {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Undefined => f.write_str("undefined"),
+      Self::Ext0 => f.write_str("ext0"),
+      Self::Ext1 => f.write_str("ext1"),
+      Self::Timer => f.write_str("timer"),
+      Self::Touchpad => f.write_str("touchpad"),
+      Self::Ulp => f.write_str("ULP"),
+      Self::Gpio => f.write_str("GPIO"),
+      Self::Uart => f.write_str("UART"),
+      Self::Unknown(raw) => write!(f, "unknown wakeup cause ({})", raw),
+    }
+  }
+}
+
+/// Get the source which caused the last wakeup from sleep
+pub fn wakeup_cause() -> WakeupCause {
+  WakeupCause::from_raw(unsafe { sys::esp_sleep_get_wakeup_cause() })
+}
+
+/// Get the bit mask of GPIOs which caused an ext1 wakeup, bit `n` is set for GPIO `n`
+///
+/// Returns 0 if the wakeup was caused by another source.
+pub fn ext1_wakeup_status() -> u64 {
+  unsafe { sys::esp_sleep_get_ext1_wakeup_status() }
+}
+
+/// Get the touch pad which caused the wakeup, `None` if the wakeup was caused by another source
+pub fn touchpad_wakeup_status() -> Option<u8> {
+  match unsafe { sys::esp_sleep_get_touchpad_wakeup_status() } {
+    sys::touch_pad_t_TOUCH_PAD_MAX => None,
+    pad => Some(pad as u8),
+  }
+}
+
+/// Returns `true` if the GPIO can be used for [SleepConfig::ext0] and [SleepConfig::ext1]
+/// wakeups, meaning it is an RTC GPIO
+pub fn is_valid_wakeup_gpio(pin: u8) -> bool {
+  VALID_GPIO_MASK & (1 << pin.min(63)) != 0
+    && unsafe { sys::esp_sleep_is_valid_wakeup_gpio(pin as _) }
+}
+
+/// Enter light sleep with the wakeup sources configured so far
+///
+/// Prefer [SleepConfig::light_sleep], which validates the wakeup sources first. Returns
+/// [EspError::InvalidState] if WiFi or BT is not stopped.
+pub fn light_sleep_start() -> Result<(), EspError> {
+  esp_result(unsafe { sys::esp_light_sleep_start() })
+}
+
+/// Enter deep sleep with the wakeup sources configured so far
+///
+/// Prefer [SleepConfig::deep_sleep], which validates the wakeup sources first. Without any
+/// wakeup source the chip sleeps until it is reset.
+pub fn deep_sleep_start() -> ! {
+  unsafe {
+    sys::esp_deep_sleep_start();
+    unreachable_unchecked()
+  }
+}
+
+/// Enter deep sleep and wake up after `duration`
+///
+/// Wakeup sources configured before are kept. Durations that do not fit in a `u64` of
+/// microseconds are saturated.
+pub fn deep_sleep(duration: Duration) -> ! {
+  let micros = u64::try_from(duration.as_micros()).unwrap_or(u64::MAX);
+  unsafe {
+    sys::esp_deep_sleep(micros);
+    unreachable_unchecked()
+  }
+}
+
+/// Set of wakeup sources, validated before entering sleep
+///
+/// On the ESP32 the following restrictions apply, violating them makes [SleepConfig::validate]
+/// return an error:
+///
+/// * ext0 can not be combined with touchpad or ULP wakeups ([EspError::InvalidState])
+/// * GPIO can not be combined with touchpad or ULP wakeups ([EspError::InvalidState])
+/// * GPIO wakeups only work for light sleep ([EspError::InvalidState])
+/// * ext0 and ext1 only work with RTC GPIOs, see [is_valid_wakeup_gpio]
+///   ([EspError::InvalidArg])
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SleepConfig {
+  timer: Option<Duration>,
+  ext0: Option<(u8, Level)>,
+  ext1: Option<(u64, Ext1Mode)>,
+  touchpad: bool,
+  ulp: bool,
+  gpio_mask: u64,
+  gpio_high: u64,
+}
+
+impl SleepConfig {
+  /// Create a configuration without any wakeup source
+  pub const fn new() -> Self {
+    SleepConfig {
+      timer: None,
+      ext0: None,
+      ext1: None,
+      touchpad: false,
+      ulp: false,
+      gpio_mask: 0,
+      gpio_high: 0,
+    }
+  }
+
+  /// Wake up after `duration`
+  pub fn timer(mut self, duration: Duration) -> Self {
+    self.timer = Some(duration);
+    self
+  }
+
+  /// Wake up when the RTC GPIO `pin` is at `level`
+  ///
+  /// Requires the RTC peripherals to be powered during sleep, which is done automatically.
+  pub fn ext0(mut self, pin: u8, level: Level) -> Self {
+    self.ext0 = Some((pin, level));
+    self
+  }
+
+  /// Wake up when the RTC GPIOs in `mask` (bit `n` for GPIO `n`) match `mode`
+  pub fn ext1(mut self, mask: u64, mode: Ext1Mode) -> Self {
+    self.ext1 = Some((mask, mode));
+    self
+  }
+
+  /// Wake up by touch sensor
+  ///
+  /// The touch pads must be configured in timer trigger mode.
+  pub fn touchpad(mut self) -> Self {
+    self.touchpad = true;
+    self
+  }
+
+  /// Wake up by the ULP coprocessor
+  pub fn ulp(mut self) -> Self {
+    self.ulp = true;
+    self
+  }
+
+  /// Wake up from light sleep when GPIO `pin` is at `level`
+  ///
+  /// Can be called for multiple pins, any of them wakes up the chip.
+  pub fn gpio(mut self, pin: u8, level: Level) -> Self {
+    let bit = 1u64 << pin.min(63);
+    self.gpio_mask |= bit;
+    match level {
+      Level::Low => self.gpio_high &= !bit,
+      Level::High => self.gpio_high |= bit,
+    }
+
+    self
+  }
+
+  /// Check that the wakeup sources can be used together in the given sleep mode
+  pub fn validate(&self, mode: SleepMode) -> Result<(), EspError> {
+    if let Some(duration) = self.timer {
+      u64::try_from(duration.as_micros()).map_err(|_| EspError::InvalidArg)?;
+    }
+
+    if let Some((pin, _)) = self.ext0 {
+      if !is_valid_wakeup_gpio(pin) {
+        return Err(EspError::InvalidArg);
+      }
+    }
+
+    if let Some((mask, _)) = self.ext1 {
+      if mask == 0 || (0..64).any(|pin| mask & (1 << pin) != 0 && !is_valid_wakeup_gpio(pin)) {
+        return Err(EspError::InvalidArg);
+      }
+    }
+
+    if self.gpio_mask & !VALID_GPIO_MASK != 0 {
+      return Err(EspError::InvalidArg);
+    }
+
+    let touch_or_ulp = self.touchpad || self.ulp;
+    if touch_or_ulp && (self.ext0.is_some() || self.gpio_mask != 0) {
+      return Err(EspError::InvalidState);
+    }
+
+    if mode == SleepMode::Deep && self.gpio_mask != 0 {
+      return Err(EspError::InvalidState);
+    }
+
+    Ok(())
+  }
+
+  /// Validate the configuration and replace all previously enabled wakeup sources with it
+  ///
+  /// This is done by [SleepConfig::light_sleep] and [SleepConfig::deep_sleep], use it together
+  /// with [light_sleep_start] or [deep_sleep_start] to enter sleep later.
+  pub fn apply(&self, mode: SleepMode) -> Result<(), EspError> {
+    self.validate(mode)?;
+
+    unsafe {
+      sys::esp_sleep_disable_wakeup_source(sys::esp_sleep_source_t_ESP_SLEEP_WAKEUP_ALL);
+
+      let stale = swap_gpio_wakeup(self.gpio_mask) & !self.gpio_mask;
+      for pin in (0..64).filter(|pin| stale & (1 << pin) != 0) {
+        esp_result(driver_sys::gpio_wakeup_disable(pin))?;
+      }
+
+      if let Some(duration) = self.timer {
+        esp_result(sys::esp_sleep_enable_timer_wakeup(
+          duration.as_micros() as u64
+        ))?;
+      }
+
+      if let Some((pin, level)) = self.ext0 {
+        let level = match level {
+          Level::Low => 0,
+          Level::High => 1,
+        };
+        esp_result(sys::esp_sleep_enable_ext0_wakeup(pin as _, level))?;
+      }
+
+      if let Some((mask, mode)) = self.ext1 {
+        esp_result(sys::esp_sleep_enable_ext1_wakeup(mask, mode.to_raw()))?;
+      }
+
+      if self.touchpad {
+        esp_result(sys::esp_sleep_enable_touchpad_wakeup())?;
+      }
+
+      if self.ulp {
+        esp_result(sys::esp_sleep_enable_ulp_wakeup())?;
+      }
+
+      if self.gpio_mask != 0 {
+        for pin in (0..64).filter(|pin| self.gpio_mask & (1 << pin) != 0) {
+          let intr_type = if self.gpio_high & (1 << pin) != 0 {
+            driver_sys::gpio_int_type_t_GPIO_INTR_HIGH_LEVEL
+          } else {
+            driver_sys::gpio_int_type_t_GPIO_INTR_LOW_LEVEL
+          };
+          esp_result(driver_sys::gpio_wakeup_enable(pin, intr_type))?;
+        }
+
+        esp_result(sys::esp_sleep_enable_gpio_wakeup())?;
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Enter light sleep, returning the wakeup cause once the chip is woken up again
+  pub fn light_sleep(&self) -> Result<WakeupCause, EspError> {
+    self.apply(SleepMode::Light)?;
+    light_sleep_start()?;
+    Ok(wakeup_cause())
+  }
+
+  /// Enter deep sleep, only returns if the configuration is invalid
+  pub fn deep_sleep(&self) -> Result<Infallible, EspError> {
+    self.apply(SleepMode::Deep)?;
+    deep_sleep_start()
+  }
+}
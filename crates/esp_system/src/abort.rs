@@ -0,0 +1,112 @@
+use core::{
+  fmt::{self, Write},
+  hint::unreachable_unchecked,
+};
+
+use esp_idf_system_sys as sys;
+
+/// Maximum length (in bytes, including the terminating NUL) of the details passed to
+/// [system_abort] and [system_abort_fmt]. Longer details are truncated.
+pub const ABORT_DETAILS_MAX: usize = 256;
+
+const ELLIPSIS: &[u8] = b"...";
+
+/// Fixed size, NUL-terminated buffer that silently truncates on overflow.
+struct DetailsBuffer {
+  buf: [u8; ABORT_DETAILS_MAX],
+  len: usize,
+  truncated: bool,
+}
+
+impl DetailsBuffer {
+  const fn new() -> Self {
+    Self {
+      buf: [0; ABORT_DETAILS_MAX],
+      len: 0,
+      truncated: false,
+    }
+  }
+
+  fn push(&mut self, s: &str) {
+    if self.truncated {
+      return;
+    }
+
+    // C strings end at the first NUL, so anything after it would be lost anyway
+    let (s, has_nul) = match s.find('\0') {
+      Some(idx) => (&s[..idx], true),
+      None => (s, false),
+    };
+
+    let available = ABORT_DETAILS_MAX - 1 - self.len;
+    let mut take = s.len().min(available);
+    while !s.is_char_boundary(take) {
+      take -= 1;
+    }
+
+    self.buf[self.len..self.len + take].copy_from_slice(&s.as_bytes()[..take]);
+    self.len += take;
+
+    if take < s.len() {
+      self.truncate();
+    } else if has_nul {
+      self.truncated = true;
+    }
+  }
+
+  /// Mark the buffer as truncated, replacing the tail with an ellipsis.
+  fn truncate(&mut self) {
+    let mut start = ABORT_DETAILS_MAX - 1 - ELLIPSIS.len();
+    // avoid leaving half of a multi-byte character in front of the ellipsis
+    while start > 0 && self.buf[start] & 0xc0 == 0x80 {
+      start -= 1;
+    }
+
+    self.buf[start..start + ELLIPSIS.len()].copy_from_slice(ELLIPSIS);
+    self.len = start + ELLIPSIS.len();
+    self.truncated = true;
+  }
+
+  fn abort(&mut self) -> ! {
+    self.buf[self.len] = 0;
+    unsafe {
+      sys::esp_system_abort(self.buf.as_ptr() as *const _);
+      unreachable_unchecked()
+    }
+  }
+}
+
+impl Write for DetailsBuffer {
+  fn write_str(&mut self, s: &str) -> fmt::Result {
+    self.push(s);
+    Ok(())
+  }
+}
+
+/// Trigger a software abort
+///
+/// The details are copied into a stack buffer of [ABORT_DETAILS_MAX] bytes, so this function
+/// does not allocate. Details that do not fit are truncated and end in `...`.
+///
+/// # Arguments
+///
+/// * `details` - Details that will be displayed during panic handling
+pub fn system_abort(details: &str) -> ! {
+  let mut buf = DetailsBuffer::new();
+  buf.push(details);
+  buf.abort()
+}
+
+/// Trigger a software abort with formatted details
+///
+/// Works like [system_abort], but formats the details directly into the stack buffer.
+///
+/// ```ignore
+/// system_abort_fmt(format_args!("invalid state: {}", state));
+/// ```
+pub fn system_abort_fmt(details: fmt::Arguments<'_>) -> ! {
+  let mut buf = DetailsBuffer::new();
+  // DetailsBuffer never fails, but user Display impls might; abort with what we have
+  let _ = buf.write_fmt(details);
+  buf.abort()
+}
@@ -0,0 +1,176 @@
+use core::{
+  mem::MaybeUninit,
+  ptr,
+  sync::atomic::{AtomicBool, Ordering},
+};
+
+use crate::{fnv::fnv1a, EspResetReason, PortMux};
+
+/// Number of reset reasons kept in the [BootHistory]
+pub const BOOT_HISTORY_LEN: usize = 8;
+
+const MAGIC: u32 = 0x424f_4f54;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct RawHistory {
+  magic: u32,
+  boot_count: u32,
+  consecutive_crashes: u32,
+  len: u32,
+  // most recent first
+  reasons: [u32; BOOT_HISTORY_LEN],
+  checksum: u32,
+}
+
+impl RawHistory {
+  const EMPTY: RawHistory = RawHistory {
+    magic: MAGIC,
+    boot_count: 0,
+    consecutive_crashes: 0,
+    len: 0,
+    reasons: [0; BOOT_HISTORY_LEN],
+    checksum: 0,
+  };
+
+  fn compute_checksum(&self) -> u32 {
+    // every field but the checksum itself
+    let words = core::iter::once(self.magic)
+      .chain(core::iter::once(self.boot_count))
+      .chain(core::iter::once(self.consecutive_crashes))
+      .chain(core::iter::once(self.len))
+      .chain(self.reasons.iter().copied());
+
+    fnv1a(words.flat_map(u32::to_le_bytes))
+  }
+
+  fn is_valid(&self) -> bool {
+    self.magic == MAGIC
+      && self.len as usize <= BOOT_HISTORY_LEN
+      && self.checksum == self.compute_checksum()
+  }
+
+  fn seal(&mut self) {
+    self.checksum = self.compute_checksum();
+  }
+}
+
+// RTC slow memory is not initialized on boot, so it survives software resets, panics,
+// watchdogs and deep sleep. After power-on it contains garbage, which fails validation.
+#[link_section = ".rtc_noinit"]
+static mut HISTORY: MaybeUninit<RawHistory> = MaybeUninit::uninit();
+
+static MUX: PortMux = PortMux::new();
+static RECORDED: AtomicBool = AtomicBool::new(false);
+
+fn with_history<R>(f: impl FnOnce(&mut RawHistory) -> R) -> R {
+  MUX.with(|| unsafe {
+    let slot = ptr::addr_of_mut!(HISTORY) as *mut RawHistory;
+    let mut history = ptr::read_volatile(slot);
+    if !history.is_valid() {
+      history = RawHistory::EMPTY;
+    }
+
+    let result = f(&mut history);
+    history.seal();
+    ptr::write_volatile(slot, history);
+    result
+  })
+}
+
+/// History of the last [BOOT_HISTORY_LEN] reset reasons, kept in RTC memory
+///
+/// The history survives every reset except for power loss. Call [BootHistory::record] early
+/// during startup, and use [BootHistory::consecutive_crashes] to decide whether to enter a
+/// safe mode:
+///
+/// ```ignore
+/// let history = BootHistory::record();
+/// if history.consecutive_crashes() >= 3 {
+///   run_safe_mode();
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BootHistory {
+  boot_count: u32,
+  consecutive_crashes: u32,
+  len: usize,
+  reasons: [EspResetReason; BOOT_HISTORY_LEN],
+}
+
+impl BootHistory {
+  /// Record the reason of the last reset and return the updated history
+  ///
+  /// Only the first call after each boot records anything, later calls behave like
+  /// [BootHistory::get].
+  pub fn record() -> Self {
+    if RECORDED.swap(true, Ordering::Relaxed) {
+      return Self::get();
+    }
+
+    let reason = EspResetReason::get();
+    with_history(|history| {
+      history.boot_count = history.boot_count.wrapping_add(1);
+      if reason.is_crash() {
+        history.consecutive_crashes = history.consecutive_crashes.saturating_add(1);
+      } else {
+        history.consecutive_crashes = 0;
+      }
+
+      history.reasons.copy_within(..BOOT_HISTORY_LEN - 1, 1);
+      history.reasons[0] = reason.to_raw();
+      history.len = (history.len + 1).min(BOOT_HISTORY_LEN as u32);
+      Self::from_raw(history)
+    })
+  }
+
+  /// Get the history without recording the last reset
+  pub fn get() -> Self {
+    with_history(|history| Self::from_raw(history))
+  }
+
+  /// Reset the consecutive crash counter, for instance once the application has been running
+  /// stable for a while
+  pub fn mark_stable() {
+    with_history(|history| history.consecutive_crashes = 0)
+  }
+
+  /// Forget all recorded resets
+  pub fn clear() {
+    with_history(|history| *history = RawHistory::EMPTY)
+  }
+
+  fn from_raw(raw: &RawHistory) -> Self {
+    let mut reasons = [EspResetReason::Unknown; BOOT_HISTORY_LEN];
+    for (reason, raw) in reasons.iter_mut().zip(raw.reasons.iter()) {
+      *reason = EspResetReason::from_raw(*raw);
+    }
+
+    BootHistory {
+      boot_count: raw.boot_count,
+      consecutive_crashes: raw.consecutive_crashes,
+      len: raw.len as usize,
+      reasons,
+    }
+  }
+
+  /// Number of boots recorded since the history was last cleared or power was lost
+  pub fn boot_count(&self) -> u32 {
+    self.boot_count
+  }
+
+  /// Number of boots in a row caused by a crash (see [EspResetReason::is_crash])
+  pub fn consecutive_crashes(&self) -> u32 {
+    self.consecutive_crashes
+  }
+
+  /// Recorded reset reasons, most recent first
+  pub fn reasons(&self) -> &[EspResetReason] {
+    &self.reasons[..self.len]
+  }
+
+  /// The most recently recorded reset reason
+  pub fn last(&self) -> Option<EspResetReason> {
+    self.reasons().first().copied()
+  }
+}
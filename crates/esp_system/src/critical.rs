@@ -0,0 +1,68 @@
+use core::{cell::UnsafeCell, marker::PhantomData};
+
+use esp_idf_freertos_sys as sys;
+
+/// A spinlock guarding critical sections (`portMUX_TYPE`)
+///
+/// Entering the critical section disables interrupts on the current core and spins until the
+/// other core has left it, so it is safe to use from both cores and from interrupt handlers.
+/// The same mux can be entered again by the core holding it. Keep critical sections short, no
+/// FreeRTOS functions that block may be called while holding one.
+///
+/// ```ignore
+/// static MUX: PortMux = PortMux::new();
+///
+/// MUX.with(|| COUNTER.set(COUNTER.get() + 1));
+/// ```
+pub struct PortMux(UnsafeCell<sys::portMUX_TYPE>);
+
+unsafe impl Send for PortMux {}
+unsafe impl Sync for PortMux {}
+
+impl PortMux {
+  pub const fn new() -> Self {
+    PortMux(UnsafeCell::new(sys::portMUX_INITIALIZER_UNLOCKED))
+  }
+
+  /// Enter the critical section, it is left when the guard is dropped
+  pub fn enter(&self) -> CriticalSectionGuard<'_> {
+    unsafe { sys::vTaskEnterCritical(self.0.get()) };
+    CriticalSectionGuard {
+      mux: self,
+      _not_send: PhantomData,
+    }
+  }
+
+  /// Run `f` inside the critical section
+  pub fn with<R>(&self, f: impl FnOnce() -> R) -> R {
+    let _guard = self.enter();
+    f()
+  }
+
+  /// The raw mux
+  pub fn as_raw(&self) -> *mut sys::portMUX_TYPE {
+    self.0.get()
+  }
+}
+
+impl Default for PortMux {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+/// A critical section entered with [PortMux::enter], left when dropped
+///
+/// Critical sections are bound to the core that entered them, so the guard can not be sent to
+/// other tasks.
+#[must_use = "the critical section is left when the guard is dropped"]
+pub struct CriticalSectionGuard<'a> {
+  mux: &'a PortMux,
+  _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for CriticalSectionGuard<'_> {
+  fn drop(&mut self) {
+    unsafe { sys::vTaskExitCritical(self.mux.0.get()) }
+  }
+}
@@ -0,0 +1,103 @@
+use core::fmt;
+
+use cstr_core::CStr;
+use esp_idf_system_sys as sys;
+
+/// Error codes returned by ESP-IDF functions (`esp_err_t`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EspError {
+  /// Generic failure
+  Fail,
+  /// Out of memory
+  NoMem,
+  /// Invalid argument
+  InvalidArg,
+  /// Invalid state
+  InvalidState,
+  /// Invalid size
+  InvalidSize,
+  /// Requested resource not found
+  NotFound,
+  /// Operation or feature not supported
+  NotSupported,
+  /// Operation timed out
+  Timeout,
+  /// Received response was invalid
+  InvalidResponse,
+  /// CRC or checksum was invalid
+  InvalidCrc,
+  /// Version was invalid
+  InvalidVersion,
+  /// MAC address was invalid
+  InvalidMac,
+  /// Any other error code, such as component specific errors
+  Other(sys::esp_err_t),
+}
+
+impl EspError {
+  /// Convert a raw `esp_err_t` into an [EspError]
+  ///
+  /// Returns `None` if `code` is `ESP_OK`.
+  pub fn from_raw(code: sys::esp_err_t) -> Option<Self> {
+    let err = match code {
+      sys::ESP_OK => return None,
+      sys::ESP_FAIL => Self::Fail,
+      sys::ESP_ERR_NO_MEM => Self::NoMem,
+      sys::ESP_ERR_INVALID_ARG => Self::InvalidArg,
+      sys::ESP_ERR_INVALID_STATE => Self::InvalidState,
+      sys::ESP_ERR_INVALID_SIZE => Self::InvalidSize,
+      sys::ESP_ERR_NOT_FOUND => Self::NotFound,
+      sys::ESP_ERR_NOT_SUPPORTED => Self::NotSupported,
+      sys::ESP_ERR_TIMEOUT => Self::Timeout,
+      sys::ESP_ERR_INVALID_RESPONSE => Self::InvalidResponse,
+      sys::ESP_ERR_INVALID_CRC => Self::InvalidCrc,
+      sys::ESP_ERR_INVALID_VERSION => Self::InvalidVersion,
+      sys::ESP_ERR_INVALID_MAC => Self::InvalidMac,
+      code => Self::Other(code),
+    };
+
+    Some(err)
+  }
+
+  /// Get the raw `esp_err_t` code of this error
+  pub fn code(self) -> sys::esp_err_t {
+    match self {
+      Self::Fail => sys::ESP_FAIL,
+      Self::NoMem => sys::ESP_ERR_NO_MEM,
+      Self::InvalidArg => sys::ESP_ERR_INVALID_ARG,
+      Self::InvalidState => sys::ESP_ERR_INVALID_STATE,
+      Self::InvalidSize => sys::ESP_ERR_INVALID_SIZE,
+      Self::NotFound => sys::ESP_ERR_NOT_FOUND,
+      Self::NotSupported => sys::ESP_ERR_NOT_SUPPORTED,
+      Self::Timeout => sys::ESP_ERR_TIMEOUT,
+      Self::InvalidResponse => sys::ESP_ERR_INVALID_RESPONSE,
+      Self::InvalidCrc => sys::ESP_ERR_INVALID_CRC,
+      Self::InvalidVersion => sys::ESP_ERR_INVALID_VERSION,
+      Self::InvalidMac => sys::ESP_ERR_INVALID_MAC,
+      Self::Other(code) => code,
+    }
+  }
+
+  /// Get the name of this error, as given by `esp_err_to_name`
+  pub fn name(self) -> &'static str {
+    let name = unsafe { CStr::from_ptr(sys::esp_err_to_name(self.code())) };
+    name.to_str().unwrap_or("UNKNOWN ERROR")
+  }
+}
+
+impl fmt::Display for EspError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{} (0x{:x})", self.name(), self.code())
+  }
+}
+
+/// Convert a raw `esp_err_t` into a [Result]
+///
+/// `ESP_OK` maps to `Ok(())`, every other code maps to the matching [EspError].
+pub fn esp_result(code: sys::esp_err_t) -> Result<(), EspError> {
+  match EspError::from_raw(code) {
+    None => Ok(()),
+    Some(err) => Err(err),
+  }
+}
@@ -0,0 +1,6 @@
+/// 32-bit FNV-1a hash, used to validate data kept in RTC memory
+pub(crate) fn fnv1a(bytes: impl IntoIterator<Item = u8>) -> u32 {
+  bytes.into_iter().fold(0x811c_9dc5, |hash, byte| {
+    (hash ^ byte as u32).wrapping_mul(0x0100_0193)
+  })
+}
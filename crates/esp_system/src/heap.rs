@@ -0,0 +1,337 @@
+//! Heap memory allocation
+//!
+//! With the `alloc` feature enabled, [EspHeap] is installed as the global allocator.
+//!
+//! Memory with specific capabilities (for instance DMA capable or external SPI RAM) can be
+//! allocated using [CapsBox] and [CapsVec], or with a [CapsAllocator] (which implements
+//! `core::alloc::Allocator` when the `allocator_api` feature is enabled).
+//!
+//! Heap tracing is available in `heap::trace` with the `heap-trace` feature.
+
+use core::{
+  alloc::{GlobalAlloc, Layout},
+  ffi::c_void,
+  fmt, mem, ptr,
+};
+
+use bitflags::bitflags;
+use esp_idf_heap_sys as sys;
+
+mod boxed;
+#[cfg(feature = "heap-trace")]
+pub mod trace;
+mod vec;
+
+pub use boxed::CapsBox;
+pub use vec::CapsVec;
+
+bitflags! {
+  /// Memory capabilities, used to select the region memory is allocated from
+  pub struct HeapCaps: u32 {
+    /// Memory must be able to run executable code
+    const EXEC = sys::MALLOC_CAP_EXEC;
+    /// Memory must allow for aligned 32-bit data accesses
+    const BIT32 = sys::MALLOC_CAP_32BIT;
+    /// Memory must allow for 8/16/...-bit data accesses
+    const BIT8 = sys::MALLOC_CAP_8BIT;
+    /// Memory must be able to accessed by DMA
+    const DMA = sys::MALLOC_CAP_DMA;
+    /// Memory must be mapped to PID2 memory space (PIDs are not currently used)
+    const PID2 = sys::MALLOC_CAP_PID2;
+    /// Memory must be mapped to PID3 memory space (PIDs are not currently used)
+    const PID3 = sys::MALLOC_CAP_PID3;
+    /// Memory must be mapped to PID4 memory space (PIDs are not currently used)
+    const PID4 = sys::MALLOC_CAP_PID4;
+    /// Memory must be mapped to PID5 memory space (PIDs are not currently used)
+    const PID5 = sys::MALLOC_CAP_PID5;
+    /// Memory must be mapped to PID6 memory space (PIDs are not currently used)
+    const PID6 = sys::MALLOC_CAP_PID6;
+    /// Memory must be mapped to PID7 memory space (PIDs are not currently used)
+    const PID7 = sys::MALLOC_CAP_PID7;
+    /// Memory must be in SPI RAM
+    const SPIRAM = sys::MALLOC_CAP_SPIRAM;
+    /// Memory must be internal; specifically it should not disappear when flash/spiram cache is switched off
+    const INTERNAL = sys::MALLOC_CAP_INTERNAL;
+    /// Memory can be returned in a non-capability-specific memory allocation (e.g. malloc(), calloc()) call
+    const DEFAULT = sys::MALLOC_CAP_DEFAULT;
+    /// Memory must be in IRAM and allow unaligned access
+    const IRAM_8BIT = sys::MALLOC_CAP_IRAM_8BIT;
+  }
+}
+
+impl HeapCaps {
+  /// Get the total free size of all the regions that have these capabilities
+  ///
+  /// Because of heap fragmentation it is probably not possible to allocate a single block of
+  /// memory of this size. Use [HeapCaps::largest_free_block] for this purpose.
+  pub fn free_size(self) -> usize {
+    unsafe { sys::heap_caps_get_free_size(self.bits()) }
+  }
+
+  /// Get the largest free block of memory able to be allocated with these capabilities
+  pub fn largest_free_block(self) -> usize {
+    unsafe { sys::heap_caps_get_largest_free_block(self.bits()) }
+  }
+
+  /// Get heap statistics for all regions with these capabilities, see [HeapInfo::get]
+  pub fn info(self) -> HeapInfo {
+    HeapInfo::get(self)
+  }
+}
+
+/// Check integrity of all heaps
+///
+/// Returns `false` if at least one heap is corrupt. If `print_errors` is set, the specific
+/// errors are printed.
+pub fn check_integrity(print_errors: bool) -> bool {
+  unsafe { sys::heap_caps_check_integrity_all(print_errors) }
+}
+
+/// Check integrity of the heap region containing `addr`
+///
+/// This has a lower overhead than [check_integrity] when debugging corruption at a known
+/// address. The entire heap region around the address is checked, not only the adjacent
+/// heap blocks. Returns `false` if the region is corrupt or `addr` doesn't belong to a heap
+/// region.
+pub fn check_integrity_addr<T>(addr: *const T, print_errors: bool) -> bool {
+  unsafe { sys::heap_caps_check_integrity_addr(addr as sys::intptr_t, print_errors) }
+}
+
+/// Heap statistics, aggregated across all regions with the requested [HeapCaps]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct HeapInfo {
+  /// Total free bytes
+  pub total_free_bytes: usize,
+  /// Total bytes allocated to data
+  pub total_allocated_bytes: usize,
+  /// Size of the largest free block. This is the largest allocatable size.
+  pub largest_free_block: usize,
+  /// Lifetime minimum free heap size
+  pub minimum_free_bytes: usize,
+  /// Number of (variable size) allocated blocks
+  pub allocated_blocks: usize,
+  /// Number of (variable size) free blocks
+  pub free_blocks: usize,
+  /// Total number of (variable size) blocks
+  pub total_blocks: usize,
+}
+
+impl HeapInfo {
+  /// Get heap statistics for all regions with the given capabilities
+  ///
+  /// `minimum_free_bytes` is the sum of the minimums of each region, which may never have been
+  /// reached at the same time.
+  pub fn get(caps: HeapCaps) -> Self {
+    let mut info = mem::MaybeUninit::uninit();
+    let info = unsafe {
+      sys::heap_caps_get_info(info.as_mut_ptr(), caps.bits());
+      info.assume_init()
+    };
+
+    HeapInfo {
+      total_free_bytes: info.total_free_bytes,
+      total_allocated_bytes: info.total_allocated_bytes,
+      largest_free_block: info.largest_free_block,
+      minimum_free_bytes: info.minimum_free_bytes,
+      allocated_blocks: info.allocated_blocks,
+      free_blocks: info.free_blocks,
+      total_blocks: info.total_blocks,
+    }
+  }
+}
+
+impl fmt::Display for HeapInfo {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "free {} B (largest block {} B, minimum {} B), allocated {} B, blocks {} allocated / {} free / {} total",
+      self.total_free_bytes,
+      self.largest_free_block,
+      self.minimum_free_bytes,
+      self.total_allocated_bytes,
+      self.allocated_blocks,
+      self.free_blocks,
+      self.total_blocks
+    )
+  }
+}
+
+/// Alignment guaranteed by the IDF heap for every allocation
+const MIN_ALIGN: usize = 4;
+
+pub(crate) unsafe fn alloc_caps(layout: Layout, caps: u32) -> *mut u8 {
+  if layout.align() <= MIN_ALIGN {
+    sys::heap_caps_malloc(layout.size(), caps) as *mut u8
+  } else {
+    sys::heap_caps_aligned_alloc(layout.align(), layout.size(), caps as _) as *mut u8
+  }
+}
+
+pub(crate) unsafe fn alloc_zeroed_caps(layout: Layout, caps: u32) -> *mut u8 {
+  if layout.align() <= MIN_ALIGN {
+    sys::heap_caps_calloc(1, layout.size(), caps) as *mut u8
+  } else {
+    let ptr = alloc_caps(layout, caps);
+    if !ptr.is_null() {
+      ptr::write_bytes(ptr, 0, layout.size());
+    }
+
+    ptr
+  }
+}
+
+pub(crate) unsafe fn dealloc_caps(ptr: *mut u8, layout: Layout) {
+  // memory from heap_caps_aligned_alloc must be freed with heap_caps_aligned_free
+  if layout.align() <= MIN_ALIGN {
+    sys::heap_caps_free(ptr as *mut c_void)
+  } else {
+    sys::heap_caps_aligned_free(ptr as *mut c_void)
+  }
+}
+
+pub(crate) unsafe fn realloc_caps(
+  ptr: *mut u8,
+  layout: Layout,
+  new_size: usize,
+  caps: u32,
+) -> *mut u8 {
+  if layout.align() <= MIN_ALIGN {
+    return sys::heap_caps_realloc(ptr as *mut c_void, new_size, caps as _) as *mut u8;
+  }
+
+  // heap_caps_realloc does not preserve alignment
+  let new_layout = Layout::from_size_align_unchecked(new_size, layout.align());
+  let new_ptr = alloc_caps(new_layout, caps);
+  if !new_ptr.is_null() {
+    ptr::copy_nonoverlapping(ptr, new_ptr, layout.size().min(new_size));
+    dealloc_caps(ptr, layout);
+  }
+
+  new_ptr
+}
+
+/// Allocator backed by the IDF heap (`heap_caps_malloc` with `MALLOC_CAP_DEFAULT`)
+///
+/// This is the memory used by `malloc()` in C code.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EspHeap;
+
+unsafe impl GlobalAlloc for EspHeap {
+  unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+    alloc_caps(layout, sys::MALLOC_CAP_DEFAULT)
+  }
+
+  unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+    alloc_zeroed_caps(layout, sys::MALLOC_CAP_DEFAULT)
+  }
+
+  unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+    dealloc_caps(ptr, layout)
+  }
+
+  unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+    realloc_caps(ptr, layout, new_size, sys::MALLOC_CAP_DEFAULT)
+  }
+}
+
+/// Allocator for memory with the given [HeapCaps]
+///
+/// Implements [GlobalAlloc], and `core::alloc::Allocator` when the `allocator_api` feature is
+/// enabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapsAllocator {
+  caps: HeapCaps,
+}
+
+impl CapsAllocator {
+  /// Memory usable by `malloc()`
+  pub const DEFAULT: Self = Self::new(HeapCaps::DEFAULT);
+  /// DMA capable memory
+  pub const DMA: Self = Self::new(HeapCaps::from_bits_truncate(
+    sys::MALLOC_CAP_DMA | sys::MALLOC_CAP_8BIT,
+  ));
+  /// Internal memory, which stays available when the flash/spiram cache is disabled
+  pub const INTERNAL: Self = Self::new(HeapCaps::from_bits_truncate(
+    sys::MALLOC_CAP_INTERNAL | sys::MALLOC_CAP_8BIT,
+  ));
+  /// External SPI RAM
+  pub const SPIRAM: Self = Self::new(HeapCaps::from_bits_truncate(
+    sys::MALLOC_CAP_SPIRAM | sys::MALLOC_CAP_8BIT,
+  ));
+  /// Memory that only supports aligned 32-bit accesses, such as IRAM
+  pub const BIT32: Self = Self::new(HeapCaps::BIT32);
+
+  /// Create an allocator for memory with the given capabilities
+  pub const fn new(caps: HeapCaps) -> Self {
+    Self { caps }
+  }
+
+  /// The capabilities memory is allocated with
+  pub const fn caps(&self) -> HeapCaps {
+    self.caps
+  }
+}
+
+unsafe impl GlobalAlloc for CapsAllocator {
+  unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+    alloc_caps(layout, self.caps.bits())
+  }
+
+  unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+    alloc_zeroed_caps(layout, self.caps.bits())
+  }
+
+  unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+    dealloc_caps(ptr, layout)
+  }
+
+  unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+    realloc_caps(ptr, layout, new_size, self.caps.bits())
+  }
+}
+
+#[cfg(feature = "allocator_api")]
+unsafe impl core::alloc::Allocator for CapsAllocator {
+  fn allocate(&self, layout: Layout) -> Result<ptr::NonNull<[u8]>, core::alloc::AllocError> {
+    let ptr = if layout.size() == 0 {
+      // zero sized allocations only need a well aligned pointer
+      layout.align() as *mut u8
+    } else {
+      unsafe { alloc_caps(layout, self.caps.bits()) }
+    };
+
+    let ptr = ptr::NonNull::new(ptr).ok_or(core::alloc::AllocError)?;
+    Ok(ptr::NonNull::slice_from_raw_parts(ptr, layout.size()))
+  }
+
+  fn allocate_zeroed(&self, layout: Layout) -> Result<ptr::NonNull<[u8]>, core::alloc::AllocError> {
+    if layout.size() == 0 {
+      return self.allocate(layout);
+    }
+
+    let ptr = unsafe { alloc_zeroed_caps(layout, self.caps.bits()) };
+    let ptr = ptr::NonNull::new(ptr).ok_or(core::alloc::AllocError)?;
+    Ok(ptr::NonNull::slice_from_raw_parts(ptr, layout.size()))
+  }
+
+  unsafe fn deallocate(&self, ptr: ptr::NonNull<u8>, layout: Layout) {
+    if layout.size() != 0 {
+      dealloc_caps(ptr.as_ptr(), layout)
+    }
+  }
+}
+
+#[cfg(feature = "alloc")]
+#[global_allocator]
+static GLOBAL: EspHeap = EspHeap;
+
+#[cfg(feature = "alloc")]
+#[alloc_error_handler]
+fn alloc_error(layout: Layout) -> ! {
+  crate::system_abort_fmt(format_args!(
+    "memory allocation of {} bytes (align {}) failed, {} bytes free",
+    layout.size(),
+    layout.align(),
+    crate::free_heap_size()
+  ))
+}
@@ -0,0 +1,174 @@
+use core::{
+  alloc::Layout,
+  fmt,
+  marker::PhantomData,
+  mem::{self, MaybeUninit},
+  ops::{Deref, DerefMut},
+  ptr::{self, NonNull},
+};
+
+use super::{alloc_caps, alloc_zeroed_caps, dealloc_caps, HeapCaps};
+use crate::EspError;
+
+/// A pointer type for a heap allocation with specific [HeapCaps]
+///
+/// Works like `Box<T>`, but does not require the `alloc` feature and lets the caller choose
+/// which memory region the value is placed in.
+///
+/// Large values should be created with [CapsBox::new_zeroed_in] or [CapsBox::new_uninit_in]
+/// and initialized in place, as [CapsBox::new_in] first builds the value on the stack:
+///
+/// ```ignore
+/// let frame = CapsBox::<[u16; 320 * 240]>::new_zeroed_in(HeapCaps::SPIRAM)?;
+/// // all zeroes is a valid `[u16; N]`
+/// let frame = unsafe { frame.assume_init() };
+/// ```
+pub struct CapsBox<T> {
+  ptr: NonNull<T>,
+  _marker: PhantomData<T>,
+}
+
+unsafe impl<T: Send> Send for CapsBox<T> {}
+unsafe impl<T: Sync> Sync for CapsBox<T> {}
+
+impl<T> CapsBox<T> {
+  /// Allocate memory with the given capabilities and move `value` into it
+  ///
+  /// Returns [EspError::NoMem] if no memory with the given capabilities is available.
+  pub fn new_in(value: T, caps: HeapCaps) -> Result<Self, EspError> {
+    let mut b = Self::new_uninit_in(caps)?;
+    unsafe {
+      b.as_mut_ptr().write(value);
+      Ok(b.assume_init())
+    }
+  }
+
+  /// Allocate uninitialized memory with the given capabilities
+  ///
+  /// Returns [EspError::NoMem] if no memory with the given capabilities is available.
+  pub fn new_uninit_in(caps: HeapCaps) -> Result<CapsBox<MaybeUninit<T>>, EspError> {
+    unsafe { CapsBox::alloc_with(caps, alloc_caps) }
+  }
+
+  /// Allocate memory with the given capabilities, filled with `0` bytes
+  ///
+  /// Returns [EspError::NoMem] if no memory with the given capabilities is available.
+  pub fn new_zeroed_in(caps: HeapCaps) -> Result<CapsBox<MaybeUninit<T>>, EspError> {
+    unsafe { CapsBox::alloc_with(caps, alloc_zeroed_caps) }
+  }
+
+  /// Consume the box, returning the wrapped raw pointer
+  ///
+  /// The memory must be released again using [CapsBox::from_raw].
+  pub fn into_raw(b: Self) -> *mut T {
+    let ptr = b.ptr.as_ptr();
+    mem::forget(b);
+    ptr
+  }
+
+  /// Construct a box from a raw pointer
+  ///
+  /// # Safety
+  ///
+  /// `ptr` must have been returned by [CapsBox::into_raw], and must not be used afterwards.
+  pub unsafe fn from_raw(ptr: *mut T) -> Self {
+    Self {
+      ptr: NonNull::new_unchecked(ptr),
+      _marker: PhantomData,
+    }
+  }
+
+  /// Consume the box, returning the wrapped value
+  pub fn into_inner(b: Self) -> T {
+    let ptr = Self::into_raw(b);
+    unsafe {
+      let value = ptr::read(ptr);
+      free::<T>(ptr);
+      value
+    }
+  }
+}
+
+impl<T> CapsBox<MaybeUninit<T>> {
+  unsafe fn alloc_with(
+    caps: HeapCaps,
+    alloc: unsafe fn(Layout, u32) -> *mut u8,
+  ) -> Result<Self, EspError> {
+    let layout = Layout::new::<T>();
+    let ptr = if layout.size() == 0 {
+      NonNull::dangling()
+    } else {
+      let ptr = alloc(layout, caps.bits()) as *mut MaybeUninit<T>;
+      NonNull::new(ptr).ok_or(EspError::NoMem)?
+    };
+
+    Ok(Self {
+      ptr,
+      _marker: PhantomData,
+    })
+  }
+
+  /// Convert to `CapsBox<T>`
+  ///
+  /// # Safety
+  ///
+  /// The value must have been initialized, see `MaybeUninit::assume_init`.
+  pub unsafe fn assume_init(self) -> CapsBox<T> {
+    let ptr = Self::into_raw(self);
+    CapsBox::from_raw(ptr as *mut T)
+  }
+}
+
+unsafe fn free<T>(ptr: *mut T) {
+  let layout = Layout::new::<T>();
+  if layout.size() != 0 {
+    dealloc_caps(ptr as *mut u8, layout);
+  }
+}
+
+impl<T> Drop for CapsBox<T> {
+  fn drop(&mut self) {
+    unsafe {
+      ptr::drop_in_place(self.ptr.as_ptr());
+      free::<T>(self.ptr.as_ptr());
+    }
+  }
+}
+
+impl<T> Deref for CapsBox<T> {
+  type Target = T;
+
+  fn deref(&self) -> &T {
+    unsafe { self.ptr.as_ref() }
+  }
+}
+
+impl<T> DerefMut for CapsBox<T> {
+  fn deref_mut(&mut self) -> &mut T {
+    unsafe { self.ptr.as_mut() }
+  }
+}
+
+impl<T> AsRef<T> for CapsBox<T> {
+  fn as_ref(&self) -> &T {
+    self
+  }
+}
+
+impl<T> AsMut<T> for CapsBox<T> {
+  fn as_mut(&mut self) -> &mut T {
+    self
+  }
+}
+
+impl<T: fmt::Debug> fmt::Debug for CapsBox<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt::Debug::fmt(&**self, f)
+  }
+}
+
+impl<T: fmt::Display> fmt::Display for CapsBox<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt::Display::fmt(&**self, f)
+  }
+}
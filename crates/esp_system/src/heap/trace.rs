@@ -0,0 +1,255 @@
+//! Heap tracing (standalone mode)
+//!
+//! Only available with the `heap-trace` feature. The application's sdkconfig must enable
+//! `CONFIG_HEAP_TRACING_STANDALONE`, and `CONFIG_HEAP_TRACING_STACK_DEPTH` must be set to
+//! [STACK_DEPTH]:
+//!
+//! - Without standalone heap tracing the `heap_trace_*` functions are not compiled into IDF, and
+//!   linking fails with undefined symbols.
+//! - With a different stack depth the IDF writes records of a different size than [TraceRecord],
+//!   corrupting memory around the trace buffer.
+//!
+//! ```ignore
+//! static mut RECORDS: [TraceRecord; 64] = [TraceRecord::EMPTY; 64];
+//!
+//! let trace = heap::trace::start(unsafe { &mut RECORDS }, TraceMode::Leaks)?;
+//! do_something();
+//! for leak in trace.stop()?.leaks() {
+//!   println!("{} bytes at {:#x} allocated by {:x?}", leak.size(), leak.address(), leak.alloced_by());
+//! }
+//! ```
+
+use core::{
+  ptr,
+  sync::atomic::{AtomicBool, Ordering},
+};
+
+use esp_idf_heap_sys as sys;
+
+use crate::{esp_result, EspError};
+
+/// Number of stack frames saved for each allocation and free
+pub const STACK_DEPTH: usize = sys::HEAP_TRACING_STACK_DEPTH;
+
+static IN_USE: AtomicBool = AtomicBool::new(false);
+
+/// Which allocations are traced
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceMode {
+  /// All heap allocations and frees are traced
+  All,
+  /// Only suspected memory leaks are traced. When memory is freed, the record is removed from
+  /// the trace buffer.
+  Leaks,
+}
+
+impl TraceMode {
+  fn to_raw(self) -> sys::heap_trace_mode_t {
+    match self {
+      Self::All => sys::heap_trace_mode_t_HEAP_TRACE_ALL,
+      Self::Leaks => sys::heap_trace_mode_t_HEAP_TRACE_LEAKS,
+    }
+  }
+}
+
+/// Information about a traced region of memory
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct TraceRecord(sys::heap_trace_record_t);
+
+// the raw pointers are only used as addresses
+unsafe impl Send for TraceRecord {}
+unsafe impl Sync for TraceRecord {}
+
+impl TraceRecord {
+  /// Empty record, used to initialize trace buffers
+  pub const EMPTY: TraceRecord = TraceRecord(sys::heap_trace_record_t {
+    ccount: 0,
+    address: ptr::null_mut(),
+    size: 0,
+    alloced_by: [ptr::null_mut(); STACK_DEPTH],
+    freed_by: [ptr::null_mut(); STACK_DEPTH],
+  });
+
+  /// CPU that made the allocation (0 or 1)
+  pub fn cpu(&self) -> u8 {
+    (self.0.ccount & 1) as u8
+  }
+
+  /// CCOUNT of the CPU when the allocation was made
+  pub fn ccount(&self) -> u32 {
+    self.0.ccount & !1
+  }
+
+  /// Address which was allocated
+  pub fn address(&self) -> usize {
+    self.0.address as usize
+  }
+
+  /// Size of the allocation
+  pub fn size(&self) -> usize {
+    self.0.size
+  }
+
+  /// Program counters of the call stack that allocated the memory
+  pub fn alloced_by(&self) -> [usize; STACK_DEPTH] {
+    let mut pcs = [0; STACK_DEPTH];
+    for (pc, raw) in pcs.iter_mut().zip(self.0.alloced_by.iter()) {
+      *pc = *raw as usize;
+    }
+
+    pcs
+  }
+
+  /// Program counters of the call stack that freed the memory (all zero if not freed)
+  pub fn freed_by(&self) -> [usize; STACK_DEPTH] {
+    let mut pcs = [0; STACK_DEPTH];
+    for (pc, raw) in pcs.iter_mut().zip(self.0.freed_by.iter()) {
+      *pc = *raw as usize;
+    }
+
+    pcs
+  }
+
+  /// Returns `true` if the memory has been freed
+  pub fn is_freed(&self) -> bool {
+    self.0.freed_by.iter().any(|pc| !pc.is_null())
+  }
+}
+
+impl core::fmt::Debug for TraceRecord {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    f.debug_struct("TraceRecord")
+      .field("cpu", &self.cpu())
+      .field("ccount", &self.ccount())
+      .field("address", &(self.address() as *const u8))
+      .field("size", &self.size())
+      .field("alloced_by", &self.alloced_by())
+      .field("freed_by", &self.freed_by())
+      .finish()
+  }
+}
+
+/// Start heap tracing, recording into `buffer`
+///
+/// The buffer must be in internal memory, not in PSRAM.
+///
+/// Only one trace can be active at a time, [EspError::InvalidState] is returned if another
+/// [HeapTraceGuard] or [HeapTraceReport] is alive.
+pub fn start(
+  buffer: &'static mut [TraceRecord],
+  mode: TraceMode,
+) -> Result<HeapTraceGuard, EspError> {
+  if IN_USE.swap(true, Ordering::Acquire) {
+    return Err(EspError::InvalidState);
+  }
+
+  let result = esp_result(unsafe {
+    sys::heap_trace_init_standalone(buffer.as_mut_ptr() as *mut _, buffer.len())
+  })
+  .and_then(|()| esp_result(unsafe { sys::heap_trace_start(mode.to_raw()) }));
+
+  match result {
+    Ok(()) => Ok(HeapTraceGuard {
+      buffer: Some(buffer),
+    }),
+    Err(e) => {
+      IN_USE.store(false, Ordering::Release);
+      Err(e)
+    }
+  }
+}
+
+/// Handle to a running heap trace
+///
+/// Use [HeapTraceGuard::stop] to get the recorded allocations. If the guard is dropped instead,
+/// tracing is stopped and the records are dumped to stdout.
+#[must_use = "tracing is stopped when the guard is dropped"]
+pub struct HeapTraceGuard {
+  buffer: Option<&'static mut [TraceRecord]>,
+}
+
+impl HeapTraceGuard {
+  /// Number of records currently in the trace buffer
+  pub fn count(&self) -> usize {
+    unsafe { sys::heap_trace_get_count() }
+  }
+
+  /// Stop tracing and return the recorded allocations
+  pub fn stop(mut self) -> Result<HeapTraceReport, EspError> {
+    esp_result(unsafe { sys::heap_trace_stop() })?;
+    Ok(HeapTraceReport {
+      buffer: self.buffer.take(),
+    })
+  }
+}
+
+impl Drop for HeapTraceGuard {
+  fn drop(&mut self) {
+    if self.buffer.is_some() {
+      unsafe {
+        sys::heap_trace_stop();
+        sys::heap_trace_dump();
+      }
+
+      release();
+    }
+  }
+}
+
+/// Records of a stopped heap trace
+pub struct HeapTraceReport {
+  buffer: Option<&'static mut [TraceRecord]>,
+}
+
+impl HeapTraceReport {
+  /// Number of records
+  pub fn len(&self) -> usize {
+    unsafe { sys::heap_trace_get_count() }
+  }
+
+  /// Returns `true` if nothing was recorded
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// Get a single record
+  pub fn get(&self, index: usize) -> Option<TraceRecord> {
+    let mut record = TraceRecord::EMPTY;
+    match esp_result(unsafe { sys::heap_trace_get(index, &mut record.0) }) {
+      Ok(()) => Some(record),
+      Err(_) => None,
+    }
+  }
+
+  /// Iterate over all records
+  pub fn records(&self) -> impl Iterator<Item = TraceRecord> + '_ {
+    (0..self.len()).filter_map(move |index| self.get(index))
+  }
+
+  /// Iterate over allocations that were not freed while tracing
+  pub fn leaks(&self) -> impl Iterator<Item = TraceRecord> + '_ {
+    self.records().filter(|record| !record.is_freed())
+  }
+
+  /// Dump the records to stdout
+  pub fn dump(&self) {
+    unsafe { sys::heap_trace_dump() }
+  }
+
+  /// Release the trace buffer so it can be used for another trace
+  pub fn into_buffer(mut self) -> &'static mut [TraceRecord] {
+    self.buffer.take().unwrap()
+  }
+}
+
+impl Drop for HeapTraceReport {
+  fn drop(&mut self) {
+    release();
+  }
+}
+
+fn release() {
+  unsafe { sys::heap_trace_init_standalone(ptr::null_mut(), 0) };
+  IN_USE.store(false, Ordering::Release);
+}
@@ -0,0 +1,235 @@
+use core::{
+  alloc::Layout,
+  fmt,
+  marker::PhantomData,
+  mem,
+  ops::{Deref, DerefMut},
+  ptr::{self, NonNull},
+  slice,
+};
+
+use super::{alloc_caps, dealloc_caps, realloc_caps, HeapCaps};
+use crate::EspError;
+
+/// A contiguous growable array allocated from memory with specific [HeapCaps]
+///
+/// Works like `Vec<T>`, but does not require the `alloc` feature and lets the caller choose
+/// which memory region the elements are placed in. Allocation failures are reported as
+/// [EspError::NoMem] instead of aborting.
+///
+/// ```ignore
+/// let mut dma_buf = CapsVec::from_elem(0u8, 4096, HeapCaps::DMA)?;
+/// spi.transfer(&mut dma_buf);
+/// ```
+pub struct CapsVec<T> {
+  ptr: NonNull<T>,
+  cap: usize,
+  len: usize,
+  caps: HeapCaps,
+  _marker: PhantomData<T>,
+}
+
+unsafe impl<T: Send> Send for CapsVec<T> {}
+unsafe impl<T: Sync> Sync for CapsVec<T> {}
+
+impl<T> CapsVec<T> {
+  const IS_ZST: bool = mem::size_of::<T>() == 0;
+
+  /// Create an empty vector. Nothing is allocated until elements are pushed.
+  pub const fn new(caps: HeapCaps) -> Self {
+    Self {
+      ptr: NonNull::dangling(),
+      cap: 0,
+      len: 0,
+      caps,
+      _marker: PhantomData,
+    }
+  }
+
+  /// Create an empty vector with space for at least `capacity` elements
+  pub fn with_capacity(capacity: usize, caps: HeapCaps) -> Result<Self, EspError> {
+    let mut vec = Self::new(caps);
+    vec.reserve(capacity)?;
+    Ok(vec)
+  }
+
+  /// The capabilities of the memory the elements are stored in
+  pub fn caps(&self) -> HeapCaps {
+    self.caps
+  }
+
+  /// Number of elements in the vector
+  pub fn len(&self) -> usize {
+    self.len
+  }
+
+  /// Returns `true` if the vector contains no elements
+  pub fn is_empty(&self) -> bool {
+    self.len == 0
+  }
+
+  /// Number of elements the vector can hold without reallocating
+  pub fn capacity(&self) -> usize {
+    if Self::IS_ZST {
+      usize::MAX
+    } else {
+      self.cap
+    }
+  }
+
+  /// Raw pointer to the vector's buffer
+  pub fn as_ptr(&self) -> *const T {
+    self.ptr.as_ptr()
+  }
+
+  /// Raw mutable pointer to the vector's buffer
+  pub fn as_mut_ptr(&mut self) -> *mut T {
+    self.ptr.as_ptr()
+  }
+
+  /// Reserve capacity for at least `additional` more elements
+  ///
+  /// Returns [EspError::NoMem] if the buffer could not be grown, in which case the vector is
+  /// left unchanged.
+  pub fn reserve(&mut self, additional: usize) -> Result<(), EspError> {
+    let required = self
+      .len
+      .checked_add(additional)
+      .ok_or(EspError::InvalidSize)?;
+    if required <= self.capacity() {
+      return Ok(());
+    }
+
+    let new_cap = required.max(self.cap * 2).max(4);
+    self.grow(new_cap)
+  }
+
+  fn grow(&mut self, new_cap: usize) -> Result<(), EspError> {
+    let new_layout = Layout::array::<T>(new_cap).map_err(|_| EspError::InvalidSize)?;
+    let ptr = unsafe {
+      if self.cap == 0 {
+        alloc_caps(new_layout, self.caps.bits())
+      } else {
+        let old_layout = Layout::array::<T>(self.cap).unwrap();
+        realloc_caps(
+          self.ptr.as_ptr() as *mut u8,
+          old_layout,
+          new_layout.size(),
+          self.caps.bits(),
+        )
+      }
+    };
+
+    self.ptr = NonNull::new(ptr as *mut T).ok_or(EspError::NoMem)?;
+    self.cap = new_cap;
+    Ok(())
+  }
+
+  /// Append an element to the back of the vector
+  ///
+  /// If the buffer could not be grown, [EspError::NoMem] is returned and `value` is dropped.
+  pub fn push(&mut self, value: T) -> Result<(), EspError> {
+    self.reserve(1)?;
+    unsafe { ptr::write(self.ptr.as_ptr().add(self.len), value) };
+    self.len += 1;
+    Ok(())
+  }
+
+  /// Remove the last element and return it, or `None` if the vector is empty
+  pub fn pop(&mut self) -> Option<T> {
+    if self.len == 0 {
+      return None;
+    }
+
+    self.len -= 1;
+    Some(unsafe { ptr::read(self.ptr.as_ptr().add(self.len)) })
+  }
+
+  /// Shorten the vector to `len` elements, dropping the rest
+  pub fn truncate(&mut self, len: usize) {
+    if len >= self.len {
+      return;
+    }
+
+    let tail = unsafe { self.ptr.as_ptr().add(len) };
+    let tail = ptr::slice_from_raw_parts_mut(tail, self.len - len);
+    self.len = len;
+    unsafe { ptr::drop_in_place(tail) };
+  }
+
+  /// Remove all elements, keeping the allocated buffer
+  pub fn clear(&mut self) {
+    self.truncate(0)
+  }
+}
+
+impl<T: Clone> CapsVec<T> {
+  /// Create a vector containing `n` clones of `elem`
+  pub fn from_elem(elem: T, n: usize, caps: HeapCaps) -> Result<Self, EspError> {
+    let mut vec = Self::with_capacity(n, caps)?;
+    for _ in 0..n {
+      vec.push(elem.clone())?;
+    }
+
+    Ok(vec)
+  }
+
+  /// Create a vector containing clones of the elements of `other`
+  pub fn from_slice(other: &[T], caps: HeapCaps) -> Result<Self, EspError> {
+    let mut vec = Self::with_capacity(other.len(), caps)?;
+    vec.extend_from_slice(other)?;
+    Ok(vec)
+  }
+
+  /// Clone and append all elements of `other`
+  pub fn extend_from_slice(&mut self, other: &[T]) -> Result<(), EspError> {
+    self.reserve(other.len())?;
+    for item in other {
+      self.push(item.clone())?;
+    }
+
+    Ok(())
+  }
+}
+
+impl<T> Drop for CapsVec<T> {
+  fn drop(&mut self) {
+    self.clear();
+    if !Self::IS_ZST && self.cap != 0 {
+      let layout = Layout::array::<T>(self.cap).unwrap();
+      unsafe { dealloc_caps(self.ptr.as_ptr() as *mut u8, layout) };
+    }
+  }
+}
+
+impl<T> Deref for CapsVec<T> {
+  type Target = [T];
+
+  fn deref(&self) -> &[T] {
+    unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+  }
+}
+
+impl<T> DerefMut for CapsVec<T> {
+  fn deref_mut(&mut self) -> &mut [T] {
+    unsafe { slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+  }
+}
+
+impl<T> AsRef<[T]> for CapsVec<T> {
+  fn as_ref(&self) -> &[T] {
+    self
+  }
+}
+
+impl<T> AsMut<[T]> for CapsVec<T> {
+  fn as_mut(&mut self) -> &mut [T] {
+    self
+  }
+}
+
+impl<T: fmt::Debug> fmt::Debug for CapsVec<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt::Debug::fmt(&**self, f)
+  }
+}
@@ -1,19 +1,42 @@
 #![no_std]
+#![cfg_attr(feature = "alloc", feature(alloc_error_handler))]
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
 
-use core::{ffi::c_void, hint::unreachable_unchecked};
+use core::{ffi::c_void, fmt, hint::unreachable_unchecked};
 
 use bitflags::bitflags;
 use esp_idf_system_sys as sys;
 
-#[non_exhaustive]
-pub enum EspMacType {
-  WifiSta,
-  WifiSoftAP,
-  Bt,
-  Eth,
-  Unknown,
-}
+mod abort;
+mod boot_history;
+mod critical;
+mod error;
+mod fnv;
+pub mod heap;
+mod mac;
+mod reset_cause;
+mod rng;
+mod rtc_cell;
+mod shutdown;
+mod system_info;
+pub mod watchdog;
 
+pub use abort::*;
+pub use boot_history::*;
+pub use critical::*;
+pub use cstr_core::CStr;
+#[cfg(feature = "alloc")]
+pub use cstr_core::CString;
+pub use error::{esp_result, EspError};
+pub use mac::*;
+pub use reset_cause::*;
+pub use rng::*;
+pub use rtc_cell::*;
+pub use shutdown::*;
+pub use system_info::*;
+
+/// Reset reasons
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[non_exhaustive]
 pub enum EspResetReason {
   /// Reset reason can not be determined
@@ -45,6 +68,7 @@ pub enum EspResetReason {
 /// This function can be called both from PRO and APP CPUs.
 /// After successful restart, CPU reset reason will be SW_CPU_RESET.
 /// Peripherals (except for WiFi, BT, UART0, SPI1, and legacy timers) are not reset.
+/// Handlers registered with [register_shutdown_handler] are run before restarting.
 /// This function does not return.
 pub fn restart() -> ! {
   unsafe {
@@ -56,7 +80,12 @@ pub fn restart() -> ! {
 impl EspResetReason {
   /// Get reason of last reset
   pub fn get() -> Self {
-    match unsafe { sys::esp_reset_reason() } {
+    Self::from_raw(unsafe { sys::esp_reset_reason() })
+  }
+
+  /// Convert a raw `esp_reset_reason_t` value, unknown values map to [EspResetReason::Unknown]
+  pub fn from_raw(raw: sys::esp_reset_reason_t) -> Self {
+    match raw {
       sys::esp_reset_reason_t_ESP_RST_POWERON => Self::Poweron,
       sys::esp_reset_reason_t_ESP_RST_EXT => Self::ExternalPin,
       sys::esp_reset_reason_t_ESP_RST_SW => Self::Software,
@@ -70,6 +99,61 @@ impl EspResetReason {
       _ => Self::Unknown,
     }
   }
+
+  /// Convert to the raw `esp_reset_reason_t` value
+  pub fn to_raw(self) -> sys::esp_reset_reason_t {
+    match self {
+      Self::Unknown => sys::esp_reset_reason_t_ESP_RST_UNKNOWN,
+      Self::Poweron => sys::esp_reset_reason_t_ESP_RST_POWERON,
+      Self::ExternalPin => sys::esp_reset_reason_t_ESP_RST_EXT,
+      Self::Software => sys::esp_reset_reason_t_ESP_RST_SW,
+      Self::Panic => sys::esp_reset_reason_t_ESP_RST_PANIC,
+      Self::InterruptWatchdog => sys::esp_reset_reason_t_ESP_RST_INT_WDT,
+      Self::TaskWatchdog => sys::esp_reset_reason_t_ESP_RST_TASK_WDT,
+      Self::OtherWatchdog => sys::esp_reset_reason_t_ESP_RST_WDT,
+      Self::Deepsleep => sys::esp_reset_reason_t_ESP_RST_DEEPSLEEP,
+      Self::Brownout => sys::esp_reset_reason_t_ESP_RST_BROWNOUT,
+      Self::SDIO => sys::esp_reset_reason_t_ESP_RST_SDIO,
+    }
+  }
+
+  /// Returns `true` if the reset was caused by a watchdog
+  pub fn is_watchdog(self) -> bool {
+    matches!(
+      self,
+      Self::InterruptWatchdog | Self::TaskWatchdog | Self::OtherWatchdog
+    )
+  }
+
+  /// Returns `true` if the reset was caused by the application crashing, meaning a panic or a
+  /// watchdog
+  pub fn is_crash(self) -> bool {
+    self == Self::Panic || self.is_watchdog()
+  }
+
+  /// Returns `true` if the application asked for the reset, using [restart] or by entering deep
+  /// sleep
+  pub fn is_intentional(self) -> bool {
+    matches!(self, Self::Software | Self::Deepsleep)
+  }
+}
+
+impl fmt::Display for EspResetReason {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(match self {
+      Self::Unknown => "unknown",
+      Self::Poweron => "power-on",
+      Self::ExternalPin => "external pin",
+      Self::Software => "software",
+      Self::Panic => "panic",
+      Self::InterruptWatchdog => "interrupt watchdog",
+      Self::TaskWatchdog => "task watchdog",
+      Self::OtherWatchdog => "other watchdog",
+      Self::Deepsleep => "deep sleep",
+      Self::Brownout => "brownout",
+      Self::SDIO => "SDIO",
+    })
+  }
 }
 
 /// Get the size of available heap.
@@ -80,6 +164,14 @@ pub fn free_heap_size() -> u32 {
   unsafe { sys::esp_get_free_heap_size() }
 }
 
+/// Get the size of available internal heap.
+///
+/// Note that the returned value may be larger than the maximum contiguous block
+/// which can be allocated.
+pub fn free_internal_heap_size() -> u32 {
+  unsafe { sys::esp_get_free_internal_heap_size() }
+}
+
 /// Get the minimum heap that has ever been available
 pub fn minimum_free_heap_size() -> u32 {
   unsafe { sys::esp_get_minimum_free_heap_size() }
@@ -90,8 +182,8 @@ pub fn minimum_free_heap_size() -> u32 {
 /// The hardware RNG is fully functional whenever an RF subsystem is running (ie Bluetooth or WiFi is enabled). For
 /// random values, call this function after WiFi or Bluetooth are started.
 ///
-/// If the RF subsystem is not used by the program, the function bootloader_random_enable() can be called to enable an
-/// entropy source. bootloader_random_disable() must be called before RF subsystem or I2S peripheral are used. See these functions'
+/// If the RF subsystem is not used by the program, the function [bootloader_random_enable] can be called to enable an
+/// entropy source. [bootloader_random_disable] must be called before RF subsystem or I2S peripheral are used. See these functions'
 /// documentation for more details.
 ///
 /// Any time the app is running without an RF subsystem (or bootloader_random) enabled, RNG hardware should be
@@ -112,29 +204,22 @@ pub fn fill_random(buf: &mut [u8]) {
   unsafe { sys::esp_fill_random(buf.as_mut_ptr() as *mut c_void, buf.len()) }
 }
 
-// TODO: Figure out how to deal with CString and alloc
-// /// Trigger a software abort
-// ///
-// /// # Arguments
-// ///
-// /// * `details` - Details that will be displayed during panic handling
-// pub fn system_abort(details: &str) -> ! {
-//   let details = CString::new(details).unwrap();
-//   unsafe {
-//     sys::esp_system_abort(details.as_ptr());
-//     unreachable_unchecked()
-//   }
-// }
-
 /// Chip models
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[non_exhaustive]
 pub enum EspChipModel {
   /// ESP32
   Esp32,
   /// ESP32-S2
   Esp32S2,
-  /// Others
-  Unknown,
+  /// ESP32-S3
+  Esp32S3,
+  /// ESP32-C3
+  Esp32C3,
+  /// ESP32-H2
+  Esp32H2,
+  /// Others, with the raw `esp_chip_model_t` value
+  Unknown(u32),
 }
 
 impl EspChipModel {
@@ -142,7 +227,23 @@ impl EspChipModel {
     match raw {
       sys::esp_chip_model_t_CHIP_ESP32 => Self::Esp32,
       sys::esp_chip_model_t_CHIP_ESP32S2 => Self::Esp32S2,
-      _ => Self::Unknown,
+      sys::CHIP_ESP32S3 => Self::Esp32S3,
+      sys::CHIP_ESP32C3 => Self::Esp32C3,
+      sys::CHIP_ESP32H2 => Self::Esp32H2,
+      raw => Self::Unknown(raw),
+    }
+  }
+}
+
+impl fmt::Display for EspChipModel {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Esp32 => f.write_str("ESP32"),
+      Self::Esp32S2 => f.write_str("ESP32-S2"),
+      Self::Esp32S3 => f.write_str("ESP32-S3"),
+      Self::Esp32C3 => f.write_str("ESP32-C3"),
+      Self::Esp32H2 => f.write_str("ESP32-H2"),
+      Self::Unknown(raw) => write!(f, "unknown chip ({})", raw),
     }
   }
 }
@@ -162,10 +263,17 @@ bitflags! {
     const BLE = bit(4);
     /// Chip has Bluetooth Classic
     const BT = bit(5);
+    /// Chip has IEEE 802.15.4
+    const IEEE802154 = bit(6);
+    /// Chip has embedded psram
+    const EMB_PSRAM = bit(7);
   }
 }
 
 /// The structure represents information about the chip
+///
+/// Displays as a human-readable summary, e.g. `ESP32 rev 1, 2 cores, WiFi/BT/BLE`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct EspChipInfo {
   /// chip model, one of [EspChipModel]
   pub model: EspChipModel,
@@ -193,3 +301,36 @@ impl EspChipInfo {
     }
   }
 }
+
+impl fmt::Display for EspChipInfo {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    const NAMES: [(ChipFeature, &str); 6] = [
+      (ChipFeature::WIFI_BGN, "WiFi"),
+      (ChipFeature::BT, "BT"),
+      (ChipFeature::BLE, "BLE"),
+      (ChipFeature::IEEE802154, "802.15.4"),
+      (ChipFeature::EMB_FLASH, "embedded flash"),
+      (ChipFeature::EMB_PSRAM, "embedded PSRAM"),
+    ];
+
+    write!(
+      f,
+      "{} rev {}, {} core",
+      self.model, self.revision, self.cores
+    )?;
+    if self.cores != 1 {
+      f.write_str("s")?;
+    }
+
+    let mut sep = ", ";
+    for (feature, name) in NAMES.iter() {
+      if self.features.contains(*feature) {
+        f.write_str(sep)?;
+        f.write_str(name)?;
+        sep = "/";
+      }
+    }
+
+    Ok(())
+  }
+}
@@ -0,0 +1,188 @@
+use core::{fmt, str::FromStr};
+
+use esp_idf_system_sys as sys;
+
+use crate::{esp_result, EspError};
+
+/// Network interface a MAC address is derived for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EspMacType {
+  /// WiFi station
+  WifiSta,
+  /// WiFi soft access point
+  WifiSoftAP,
+  /// Bluetooth
+  Bt,
+  /// Ethernet
+  Eth,
+  /// Others
+  Unknown,
+}
+
+impl EspMacType {
+  fn to_raw(self) -> Option<sys::esp_mac_type_t> {
+    match self {
+      Self::WifiSta => Some(sys::esp_mac_type_t_ESP_MAC_WIFI_STA),
+      Self::WifiSoftAP => Some(sys::esp_mac_type_t_ESP_MAC_WIFI_SOFTAP),
+      Self::Bt => Some(sys::esp_mac_type_t_ESP_MAC_BT),
+      Self::Eth => Some(sys::esp_mac_type_t_ESP_MAC_ETH),
+      Self::Unknown => None,
+    }
+  }
+}
+
+/// A 6 byte (EUI-48) MAC address
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct MacAddress(pub [u8; 6]);
+
+impl MacAddress {
+  /// Create a MAC address from its bytes
+  pub const fn new(bytes: [u8; 6]) -> Self {
+    Self(bytes)
+  }
+
+  /// The bytes of the MAC address
+  pub const fn octets(&self) -> [u8; 6] {
+    self.0
+  }
+
+  /// Returns `true` if this is a unicast address (least significant bit of the first byte is zero)
+  pub const fn is_unicast(&self) -> bool {
+    self.0[0] & 0x01 == 0
+  }
+
+  /// Returns `true` if this is a multicast address (least significant bit of the first byte is one)
+  pub const fn is_multicast(&self) -> bool {
+    !self.is_unicast()
+  }
+
+  /// Returns `true` if the "locally administered" bit (bit value 0x02 in the first byte) is set
+  pub const fn is_locally_administered(&self) -> bool {
+    self.0[0] & 0x02 != 0
+  }
+
+  /// Returns `true` if this is a universally administered address (uses a valid OUI)
+  pub const fn is_universal(&self) -> bool {
+    !self.is_locally_administered()
+  }
+}
+
+impl From<[u8; 6]> for MacAddress {
+  fn from(bytes: [u8; 6]) -> Self {
+    Self(bytes)
+  }
+}
+
+impl From<MacAddress> for [u8; 6] {
+  fn from(mac: MacAddress) -> Self {
+    mac.0
+  }
+}
+
+impl fmt::Display for MacAddress {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let [a, b, c, d, e, g] = self.0;
+    write!(
+      f,
+      "{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
+      a, b, c, d, e, g
+    )
+  }
+}
+
+/// Error returned when parsing a [MacAddress] from a string fails
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseMacAddressError(());
+
+impl fmt::Display for ParseMacAddressError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str("invalid MAC address syntax")
+  }
+}
+
+impl FromStr for MacAddress {
+  type Err = ParseMacAddressError;
+
+  /// Parse a MAC address in colon separated hex form (`aa:bb:cc:dd:ee:ff`)
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let mut bytes = [0u8; 6];
+    let mut parts = s.split(':');
+    for byte in bytes.iter_mut() {
+      let part = parts.next().ok_or(ParseMacAddressError(()))?;
+      // from_str_radix alone would also accept a sign, like "+a"
+      if part.len() != 2 || !part.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(ParseMacAddressError(()));
+      }
+
+      *byte = u8::from_str_radix(part, 16).map_err(|_| ParseMacAddressError(()))?;
+    }
+
+    match parts.next() {
+      None => Ok(Self(bytes)),
+      Some(_) => Err(ParseMacAddressError(())),
+    }
+  }
+}
+
+fn get_mac(f: impl FnOnce(*mut u8) -> sys::esp_err_t) -> Result<MacAddress, EspError> {
+  let mut mac = MacAddress::default();
+  esp_result(f(mac.0.as_mut_ptr()))?;
+  Ok(mac)
+}
+
+/// Read base MAC address and derive the MAC address of the given interface
+///
+/// This function first gets the base MAC address using [base_mac] or reads the base MAC address
+/// from BLK0 of EFUSE. Then it derives the MAC address of the interface including wifi station,
+/// wifi softap, bluetooth and ethernet.
+///
+/// Returns [EspError::InvalidArg] for [EspMacType::Unknown].
+pub fn read_mac(ty: EspMacType) -> Result<MacAddress, EspError> {
+  let ty = ty.to_raw().ok_or(EspError::InvalidArg)?;
+  get_mac(|mac| unsafe { sys::esp_read_mac(mac, ty) })
+}
+
+/// Return base MAC address which is set using [set_base_mac]
+///
+/// Returns [EspError::InvalidMac] if the base MAC address has not been set.
+pub fn base_mac() -> Result<MacAddress, EspError> {
+  get_mac(|mac| unsafe { sys::esp_base_mac_addr_get(mac) })
+}
+
+/// Set base MAC address with the MAC address which is stored in BLK3 of EFUSE or
+/// external storage e.g. flash and EEPROM.
+///
+/// Base MAC address is used to generate the MAC addresses used by the networking interfaces.
+/// If using base MAC address stored in BLK3 of EFUSE or external storage, call this function to set
+/// base MAC address before initializing WiFi/BT/Ethernet.
+///
+/// Base MAC must be a unicast MAC (least significant bit of first byte must be zero), otherwise
+/// [EspError::InvalidArg] is returned. If not using a valid OUI, set the "locally administered" bit
+/// (bit value 0x02 in the first byte) to avoid collisions.
+pub fn set_base_mac(mac: MacAddress) -> Result<(), EspError> {
+  esp_result(unsafe { sys::esp_base_mac_addr_set(mac.0.as_ptr()) })
+}
+
+/// Return base MAC address which is factory-programmed by Espressif in BLK0 of EFUSE.
+pub fn factory_mac() -> Result<MacAddress, EspError> {
+  get_mac(|mac| unsafe { sys::esp_efuse_mac_get_default(mac) })
+}
+
+/// Return base MAC address which was previously written to BLK3 of EFUSE.
+///
+/// Writing this EFUSE allows setting of a different (non-Espressif) base MAC address.
+///
+/// Returns [EspError::InvalidVersion] if an invalid MAC version field was read from BLK3 of EFUSE,
+/// and [EspError::InvalidCrc] if an invalid MAC CRC was read.
+pub fn custom_efuse_mac() -> Result<MacAddress, EspError> {
+  get_mac(|mac| unsafe { sys::esp_efuse_mac_get_custom(mac) })
+}
+
+/// Derive local MAC address from universal MAC address.
+///
+/// See <https://en.wikipedia.org/wiki/MAC_address#Universal_vs._local> for the definition of
+/// local vs universal MAC addresses.
+pub fn derive_local_mac(universal_mac: MacAddress) -> Result<MacAddress, EspError> {
+  get_mac(|mac| unsafe { sys::esp_derive_local_mac(mac, universal_mac.0.as_ptr()) })
+}
@@ -0,0 +1,169 @@
+use core::fmt;
+
+use bitflags::bitflags;
+use esp_idf_esp_rom_sys as rom_sys;
+
+/// CPU cores of the ESP32
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Core {
+  /// Protocol CPU (core 0)
+  Pro,
+  /// Application CPU (core 1)
+  App,
+}
+
+impl Core {
+  /// Index of the core, as used by IDF functions taking a core ID
+  pub fn id(self) -> u32 {
+    match self {
+      Self::Pro => 0,
+      Self::App => 1,
+    }
+  }
+}
+
+/// Low-level reset cause of a single CPU, as reported by the ROM (`RESET_REASON`)
+///
+/// Unlike [EspResetReason](crate::EspResetReason) this tells the hardware sources apart, e.g.
+/// which timer group watchdog fired or whether the RTC watchdog reset on a brownout. The names
+/// printed by [Display](fmt::Display) match the ones in the ROM boot log (`rst:0x7
+/// (TG0WDT_SYS_RESET)`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CpuResetCause {
+  /// Vbat power on reset
+  PowerOn,
+  /// Software reset of the digital core
+  Software,
+  /// Legacy watchdog reset of the digital core
+  LegacyWatchdog,
+  /// Deep sleep reset of the digital core
+  DeepSleep,
+  /// Reset of the digital core by the SLC module
+  Sdio,
+  /// Timer group 0 watchdog reset of the digital core
+  TimerGroup0Watchdog,
+  /// Timer group 1 watchdog reset of the digital core
+  TimerGroup1Watchdog,
+  /// RTC watchdog reset of the digital core
+  RtcWatchdog,
+  /// Intrusion test reset of the CPU
+  Intrusion,
+  /// Timer group watchdog reset of the CPU
+  TimerGroupWatchdogCpu,
+  /// Software reset of the CPU
+  SoftwareCpu,
+  /// RTC watchdog reset of the CPU
+  RtcWatchdogCpu,
+  /// APP CPU reset by the PRO CPU
+  ExternalCpu,
+  /// Reset when the VDD voltage is not stable (brownout)
+  BrownOut,
+  /// RTC watchdog reset of the digital core and RTC module
+  RtcWatchdogRtc,
+  /// Others, with the raw `RESET_REASON` value
+  Unknown(u32),
+}
+
+impl CpuResetCause {
+  /// Get the reset cause of the given CPU core
+  pub fn for_core(core: Core) -> Self {
+    Self::from_raw(unsafe { rom_sys::rtc_get_reset_reason(core.id() as _) })
+  }
+
+  /// Convert a raw `RESET_REASON` value
+  pub fn from_raw(raw: rom_sys::RESET_REASON) -> Self {
+    match raw {
+      rom_sys::RESET_REASON_POWERON_RESET => Self::PowerOn,
+      rom_sys::RESET_REASON_SW_RESET => Self::Software,
+      rom_sys::RESET_REASON_OWDT_RESET => Self::LegacyWatchdog,
+      rom_sys::RESET_REASON_DEEPSLEEP_RESET => Self::DeepSleep,
+      rom_sys::RESET_REASON_SDIO_RESET => Self::Sdio,
+      rom_sys::RESET_REASON_TG0WDT_SYS_RESET => Self::TimerGroup0Watchdog,
+      rom_sys::RESET_REASON_TG1WDT_SYS_RESET => Self::TimerGroup1Watchdog,
+      rom_sys::RESET_REASON_RTCWDT_SYS_RESET => Self::RtcWatchdog,
+      rom_sys::RESET_REASON_INTRUSION_RESET => Self::Intrusion,
+      rom_sys::RESET_REASON_TGWDT_CPU_RESET => Self::TimerGroupWatchdogCpu,
+      rom_sys::RESET_REASON_SW_CPU_RESET => Self::SoftwareCpu,
+      rom_sys::RESET_REASON_RTCWDT_CPU_RESET => Self::RtcWatchdogCpu,
+      rom_sys::RESET_REASON_EXT_CPU_RESET => Self::ExternalCpu,
+      rom_sys::RESET_REASON_RTCWDT_BROWN_OUT_RESET => Self::BrownOut,
+      rom_sys::RESET_REASON_RTCWDT_RTC_RESET => Self::RtcWatchdogRtc,
+      raw => Self::Unknown(raw),
+    }
+  }
+
+  /// Returns `true` if the reset was caused by one of the hardware watchdogs
+  pub fn is_watchdog(self) -> bool {
+    matches!(
+      self,
+      Self::LegacyWatchdog
+        | Self::TimerGroup0Watchdog
+        | Self::TimerGroup1Watchdog
+        | Self::RtcWatchdog
+        | Self::TimerGroupWatchdogCpu
+        | Self::RtcWatchdogCpu
+        | Self::RtcWatchdogRtc
+    )
+  }
+}
+
+impl fmt::Display for CpuResetCause {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::PowerOn => f.write_str("POWERON_RESET"),
+      Self::Software => f.write_str("SW_RESET"),
+      Self::LegacyWatchdog => f.write_str("OWDT_RESET"),
+      Self::DeepSleep => f.write_str("DEEPSLEEP_RESET"),
+      Self::Sdio => f.write_str("SDIO_RESET"),
+      Self::TimerGroup0Watchdog => f.write_str("TG0WDT_SYS_RESET"),
+      Self::TimerGroup1Watchdog => f.write_str("TG1WDT_SYS_RESET"),
+      Self::RtcWatchdog => f.write_str("RTCWDT_SYS_RESET"),
+      Self::Intrusion => f.write_str("INTRUSION_RESET"),
+      Self::TimerGroupWatchdogCpu => f.write_str("TGWDT_CPU_RESET"),
+      Self::SoftwareCpu => f.write_str("SW_CPU_RESET"),
+      Self::RtcWatchdogCpu => f.write_str("RTCWDT_CPU_RESET"),
+      Self::ExternalCpu => f.write_str("EXT_CPU_RESET"),
+      Self::BrownOut => f.write_str("RTCWDT_BROWN_OUT_RESET"),
+      Self::RtcWatchdogRtc => f.write_str("RTCWDT_RTC_RESET"),
+      Self::Unknown(raw) => write!(f, "unknown reset cause ({})", raw),
+    }
+  }
+}
+
+bitflags! {
+  /// Low-level wakeup cause, as reported by the ROM (`WAKEUP_REASON`)
+  ///
+  /// Empty if the chip did not wake up from sleep.
+  pub struct CpuWakeupCause: u32 {
+    /// External wakeup source 0 (EXT0)
+    const EXT_EVENT0 = rom_sys::WAKEUP_REASON_EXT_EVENT0_TRIG;
+    /// External wakeup source 1 (EXT1)
+    const EXT_EVENT1 = rom_sys::WAKEUP_REASON_EXT_EVENT1_TRIG;
+    /// GPIO (light sleep only)
+    const GPIO = rom_sys::WAKEUP_REASON_GPIO_TRIG;
+    /// RTC timer
+    const TIMER = rom_sys::WAKEUP_REASON_TIMER_EXPIRE;
+    /// SDIO (light sleep only)
+    const SDIO = rom_sys::WAKEUP_REASON_SDIO_TRIG;
+    /// WiFi MAC (light sleep only)
+    const MAC = rom_sys::WAKEUP_REASON_MAC_TRIG;
+    /// UART0 (light sleep only)
+    const UART0 = rom_sys::WAKEUP_REASON_UART0_TRIG;
+    /// UART1 (light sleep only)
+    const UART1 = rom_sys::WAKEUP_REASON_UART1_TRIG;
+    /// Touchpad
+    const TOUCH = rom_sys::WAKEUP_REASON_TOUCH_TRIG;
+    /// ULP coprocessor
+    const SAR = rom_sys::WAKEUP_REASON_SAR_TRIG;
+    /// Bluetooth (light sleep only)
+    const BT = rom_sys::WAKEUP_REASON_BT_TRIG;
+  }
+}
+
+impl CpuWakeupCause {
+  /// Get the wakeup cause of the last wakeup from sleep
+  pub fn get() -> Self {
+    Self::from_bits_truncate(unsafe { rom_sys::rtc_get_wakeup_cause() })
+  }
+}
@@ -0,0 +1,150 @@
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use esp_idf_bootloader_support_sys as boot_sys;
+
+static BOOTLOADER_ENTROPY: AtomicBool = AtomicBool::new(false);
+static RF_ENTROPY: AtomicBool = AtomicBool::new(false);
+
+/// Enable an entropy source for RNG if RF is disabled
+///
+/// All SoCs use the SAR ADC to continuously mix random bits (an internal noise reading) into
+/// the hardware RNG. Call this if true random numbers are required without an initialized RF
+/// subsystem, for instance early during startup or if RF is kept off for power saving.
+///
+/// # Warning
+///
+/// This function must not be used while any other subsystem is accessing the RF subsystem or
+/// the ADC. Call [bootloader_random_disable] before RF subsystem features, ADC or I2S are
+/// initialized.
+pub fn bootloader_random_enable() {
+  unsafe { boot_sys::bootloader_random_enable() };
+  BOOTLOADER_ENTROPY.store(true, Ordering::Release);
+}
+
+/// Disable the entropy source enabled by [bootloader_random_enable]
+///
+/// Must be called before RF subsystem features, ADC, or I2S are initialized.
+pub fn bootloader_random_disable() {
+  BOOTLOADER_ENTROPY.store(false, Ordering::Release);
+  unsafe { boot_sys::bootloader_random_disable() };
+}
+
+/// Record whether an RF subsystem (WiFi or Bluetooth) is running.
+///
+/// The RF subsystem can not be detected from here, so whatever starts or stops WiFi or
+/// Bluetooth should report it. This only affects [entropy_source_active].
+pub fn set_rf_entropy_active(active: bool) {
+  RF_ENTROPY.store(active, Ordering::Release);
+}
+
+/// Returns `true` if an entropy source is known to be active, meaning the hardware RNG
+/// produces true random numbers.
+///
+/// This is the case when [bootloader_random_enable] has been called, or an RF subsystem was
+/// reported running using [set_rf_entropy_active].
+pub fn entropy_source_active() -> bool {
+  BOOTLOADER_ENTROPY.load(Ordering::Acquire) || RF_ENTROPY.load(Ordering::Acquire)
+}
+
+/// `getrandom` backend, so crates depending on `getrandom` work on the ESP32.
+///
+/// Same as [fill_random](crate::fill_random), the output is only truly random while an entropy
+/// source is active (see [entropy_source_active]).
+#[cfg(feature = "getrandom")]
+fn getrandom_custom(dest: &mut [u8]) -> Result<(), getrandom::Error> {
+  crate::fill_random(dest);
+  Ok(())
+}
+
+#[cfg(feature = "getrandom")]
+getrandom::register_custom_getrandom!(getrandom_custom);
+
+#[cfg(feature = "rand_core")]
+pub use self::rand::*;
+
+#[cfg(feature = "rand_core")]
+mod rand {
+  use core::num::NonZeroU32;
+
+  use rand_core::{impls, CryptoRng, Error, RngCore};
+
+  use super::entropy_source_active;
+  use crate::{fill_random, random};
+
+  /// Error code returned by [EspSecureRng::try_fill_bytes] when no entropy source is active
+  pub const NO_ENTROPY_ERROR: u32 = Error::CUSTOM_START;
+
+  /// [RngCore] backed by the hardware RNG
+  ///
+  /// Without an active entropy source the hardware RNG should be considered a PRNG, so this type
+  /// does not implement [CryptoRng]. Use [EspRng::try_secure] to get a cryptographically secure
+  /// generator.
+  #[derive(Debug, Clone, Copy, Default)]
+  pub struct EspRng;
+
+  impl EspRng {
+    /// Get a cryptographically secure generator
+    ///
+    /// Returns `None` if neither the RF subsystem nor the bootloader entropy source is known to
+    /// be active, see [entropy_source_active](super::entropy_source_active).
+    pub fn try_secure() -> Option<EspSecureRng> {
+      if entropy_source_active() {
+        Some(EspSecureRng(()))
+      } else {
+        None
+      }
+    }
+  }
+
+  impl RngCore for EspRng {
+    fn next_u32(&mut self) -> u32 {
+      random()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+      impls::next_u64_via_u32(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+      fill_random(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+      fill_random(dest);
+      Ok(())
+    }
+  }
+
+  /// Cryptographically secure [RngCore] backed by the hardware RNG
+  ///
+  /// Created by [EspRng::try_secure] while an entropy source is active. Disabling the entropy
+  /// source afterwards is not detected by [RngCore::fill_bytes], but makes
+  /// [RngCore::try_fill_bytes] fail with [NO_ENTROPY_ERROR].
+  #[derive(Debug, Clone, Copy)]
+  pub struct EspSecureRng(());
+
+  impl RngCore for EspSecureRng {
+    fn next_u32(&mut self) -> u32 {
+      random()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+      impls::next_u64_via_u32(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+      fill_random(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+      if !entropy_source_active() {
+        return Err(NonZeroU32::new(NO_ENTROPY_ERROR).unwrap().into());
+      }
+
+      fill_random(dest);
+      Ok(())
+    }
+  }
+
+  impl CryptoRng for EspSecureRng {}
+}
@@ -0,0 +1,198 @@
+use core::{cell::UnsafeCell, mem::MaybeUninit, ptr};
+
+use crate::{fnv::fnv1a, PortMux};
+
+const MAGIC: u32 = 0x5254_4331;
+
+/// Types without padding or uninitialized bytes, which can be stored in a [RtcCell]
+///
+/// The cell checksums the bytes of the stored value, which must therefore all be initialized.
+///
+/// # Safety
+///
+/// The type must not contain padding, `MaybeUninit`, unions, pointers or references. Prefer
+/// `#[repr(C)]` structs whose fields are all `RtcValue`, laid out without gaps.
+pub unsafe trait RtcValue: Copy + 'static {}
+
+macro_rules! rtc_value {
+  ($($ty:ty),*) => {
+    $(unsafe impl RtcValue for $ty {})*
+  };
+}
+
+rtc_value!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, bool, char);
+
+macro_rules! rtc_value_array {
+  ($($len:literal)*) => {
+    $(unsafe impl<T: RtcValue> RtcValue for [T; $len] {})*
+  };
+}
+
+rtc_value_array!(
+  0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32
+  48 64 96 128 256 512 1024
+);
+
+/// Backing storage of a [RtcCell], placed in RTC memory by [rtc_persistent] and [rtc_noinit]
+#[repr(C)]
+pub struct RtcStorage<T: RtcValue> {
+  magic: UnsafeCell<u32>,
+  value: UnsafeCell<MaybeUninit<T>>,
+  checksum: UnsafeCell<u32>,
+}
+
+// only accessed through RtcCell, which serializes access
+unsafe impl<T: RtcValue + Send> Sync for RtcStorage<T> {}
+
+impl<T: RtcValue> RtcStorage<T> {
+  #[doc(hidden)]
+  pub const fn new() -> Self {
+    RtcStorage {
+      magic: UnsafeCell::new(0),
+      value: UnsafeCell::new(MaybeUninit::uninit()),
+      checksum: UnsafeCell::new(0),
+    }
+  }
+}
+
+// FNV-1a over the magic and the stored bytes of the value
+unsafe fn checksum<T: RtcValue>(value: *const MaybeUninit<T>) -> u32 {
+  let bytes = value as *const u8;
+  let value_bytes = (0..core::mem::size_of::<T>()).map(|i| ptr::read_volatile(bytes.add(i)));
+  fnv1a(MAGIC.to_le_bytes().iter().copied().chain(value_bytes))
+}
+
+/// A value in RTC memory, which survives deep sleep
+///
+/// Declare cells with [rtc_persistent] or [rtc_noinit]. The stored value is guarded by a magic
+/// number and a checksum, so when the memory contents are lost or corrupted (e.g. after a cold
+/// boot) the cell reads as its initial value again. Values must implement [RtcValue].
+///
+/// Accesses are serialized with a [PortMux] critical section, so they are safe from both cores
+/// and from interrupt handlers. Closures passed to [RtcCell::update] run inside the critical
+/// section and must be short.
+///
+/// ```ignore
+/// rtc_persistent! {
+///   static WAKEUPS: RtcCell<u32> = 0;
+/// }
+///
+/// let wakeups = WAKEUPS.update(|n| n + 1);
+/// ```
+pub struct RtcCell<T: RtcValue> {
+  storage: &'static RtcStorage<T>,
+  init: T,
+  mux: PortMux,
+}
+
+unsafe impl<T: RtcValue + Send> Sync for RtcCell<T> {}
+
+impl<T: RtcValue> RtcCell<T> {
+  #[doc(hidden)]
+  pub const fn new(storage: &'static RtcStorage<T>, init: T) -> Self {
+    RtcCell {
+      storage,
+      init,
+      mux: PortMux::new(),
+    }
+  }
+
+  fn with<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+    self.mux.with(|| unsafe {
+      let storage = self.storage;
+      // the bytes are validated before they are interpreted as a T
+      let valid = ptr::read_volatile(storage.magic.get()) == MAGIC
+        && ptr::read_volatile(storage.checksum.get()) == checksum(storage.value.get());
+      let mut value = if valid {
+        ptr::read_volatile(storage.value.get()).assume_init()
+      } else {
+        self.init
+      };
+
+      let result = f(&mut value);
+      ptr::write_volatile(storage.value.get(), MaybeUninit::new(value));
+      ptr::write_volatile(storage.checksum.get(), checksum(storage.value.get()));
+      ptr::write_volatile(storage.magic.get(), MAGIC);
+      result
+    })
+  }
+
+  /// Get the stored value, or the initial value if RTC memory did not hold a valid one
+  pub fn get(&self) -> T {
+    self.with(|value| *value)
+  }
+
+  /// Store a value
+  pub fn set(&self, value: T) {
+    self.with(|stored| *stored = value)
+  }
+
+  /// Store a value, returning the previous one
+  pub fn replace(&self, value: T) -> T {
+    self.with(|stored| core::mem::replace(stored, value))
+  }
+
+  /// Update the stored value using `f`, returning the new value
+  pub fn update(&self, f: impl FnOnce(T) -> T) -> T {
+    self.with(|stored| {
+      *stored = f(*stored);
+      *stored
+    })
+  }
+
+  /// Restore the initial value
+  pub fn reset(&self) {
+    let init = self.init;
+    self.set(init)
+  }
+}
+
+/// Declare [RtcCell] statics in RTC slow memory (`.rtc.data`)
+///
+/// The values survive deep sleep. All other resets, including software resets and panics, restore
+/// the initial values, as the bootloader reloads `.rtc.data`. Use [rtc_noinit] for values which
+/// should survive those too.
+///
+/// ```ignore
+/// rtc_persistent! {
+///   /// Number of deep sleep wakeups since the last reset
+///   pub static WAKEUPS: RtcCell<u32> = 0;
+/// }
+/// ```
+#[macro_export]
+macro_rules! rtc_persistent {
+  ($($(#[$attr:meta])* $vis:vis static $name:ident: RtcCell<$ty:ty> = $init:expr;)*) => {
+    $(
+      $(#[$attr])*
+      $vis static $name: $crate::RtcCell<$ty> = {
+        #[link_section = ".rtc.data"]
+        static STORAGE: $crate::RtcStorage<$ty> = $crate::RtcStorage::new();
+        $crate::RtcCell::new(&STORAGE, $init)
+      };
+    )*
+  };
+}
+
+/// Declare [RtcCell] statics in uninitialized RTC slow memory (`.rtc_noinit`)
+///
+/// The values survive deep sleep and every kind of reset, only power loss restores the initial
+/// values.
+///
+/// ```ignore
+/// rtc_noinit! {
+///   static LAST_ERROR: RtcCell<u32> = 0;
+/// }
+/// ```
+#[macro_export]
+macro_rules! rtc_noinit {
+  ($($(#[$attr:meta])* $vis:vis static $name:ident: RtcCell<$ty:ty> = $init:expr;)*) => {
+    $(
+      $(#[$attr])*
+      $vis static $name: $crate::RtcCell<$ty> = {
+        #[link_section = ".rtc_noinit"]
+        static STORAGE: $crate::RtcStorage<$ty> = $crate::RtcStorage::new();
+        $crate::RtcCell::new(&STORAGE, $init)
+      };
+    )*
+  };
+}
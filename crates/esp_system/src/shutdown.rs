@@ -0,0 +1,141 @@
+use core::{
+  mem,
+  sync::atomic::{AtomicBool, AtomicU32, AtomicUsize, Ordering},
+};
+
+use esp_idf_system_sys as sys;
+
+use crate::{esp_result, EspError, PortMux};
+
+/// Maximum number of shutdown handlers that can be registered through [register_shutdown_handler]
+pub const SHUTDOWN_HANDLER_SLOTS: usize = 8;
+
+struct Slot {
+  handler: AtomicUsize,
+  seq: AtomicU32,
+}
+
+impl Slot {
+  // only used to initialize SLOTS
+  #[allow(clippy::declare_interior_mutable_const)]
+  const EMPTY: Slot = Slot {
+    handler: AtomicUsize::new(0),
+    seq: AtomicU32::new(0),
+  };
+}
+
+static SLOTS: [Slot; SHUTDOWN_HANDLER_SLOTS] = [Slot::EMPTY; SHUTDOWN_HANDLER_SLOTS];
+static NEXT_SEQ: AtomicU32 = AtomicU32::new(0);
+// serializes registration, the dispatcher only reads the slots
+static MUX: PortMux = PortMux::new();
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+/// Runs every registered handler, ordered by registration.
+unsafe extern "C" fn run_shutdown_handlers() {
+  let mut last = None;
+  loop {
+    let mut next: Option<(u32, usize)> = None;
+    for slot in SLOTS.iter() {
+      let handler = slot.handler.load(Ordering::Acquire);
+      if handler == 0 {
+        continue;
+      }
+
+      let seq = slot.seq.load(Ordering::Relaxed);
+      let after_last = match last {
+        None => true,
+        Some(last) => seq > last,
+      };
+      let before_next = match next {
+        None => true,
+        Some((next, _)) => seq < next,
+      };
+      if after_last && before_next {
+        next = Some((seq, handler));
+      }
+    }
+
+    match next {
+      None => break,
+      Some((seq, handler)) => {
+        last = Some(seq);
+        let handler = mem::transmute::<usize, fn()>(handler);
+        handler();
+      }
+    }
+  }
+}
+
+fn install() -> Result<(), EspError> {
+  if INSTALLED.load(Ordering::Acquire) {
+    return Ok(());
+  }
+
+  match esp_result(unsafe { sys::esp_register_shutdown_handler(Some(run_shutdown_handlers)) }) {
+    // another task won the race to install the dispatcher
+    Ok(()) | Err(EspError::InvalidState) => {
+      INSTALLED.store(true, Ordering::Release);
+      Ok(())
+    }
+    Err(e) => Err(e),
+  }
+}
+
+/// Guard for a registered shutdown handler.
+///
+/// The handler is unregistered when the guard is dropped. Use [ShutdownHandler::leak] to keep the
+/// handler registered for the rest of the program.
+#[must_use = "the shutdown handler is unregistered when the guard is dropped"]
+pub struct ShutdownHandler {
+  slot: usize,
+}
+
+impl ShutdownHandler {
+  /// Keep the handler registered forever.
+  pub fn leak(self) {
+    mem::forget(self)
+  }
+}
+
+impl Drop for ShutdownHandler {
+  fn drop(&mut self) {
+    SLOTS[self.slot].handler.store(0, Ordering::Release);
+  }
+}
+
+/// Register a handler that gets invoked before the application is restarted using [restart](crate::restart).
+///
+/// Handlers registered through this function are run in registration order. At most
+/// [SHUTDOWN_HANDLER_SLOTS] handlers can be registered at the same time.
+///
+/// # Errors
+///
+/// * [EspError::InvalidState] if the handler has already been registered
+/// * [EspError::NoMem] if no more shutdown handler slots are available
+pub fn register_shutdown_handler(handler: fn()) -> Result<ShutdownHandler, EspError> {
+  let handler = handler as usize;
+  install()?;
+
+  MUX.with(|| {
+    if SLOTS
+      .iter()
+      .any(|slot| slot.handler.load(Ordering::Relaxed) == handler)
+    {
+      return Err(EspError::InvalidState);
+    }
+
+    let index = SLOTS
+      .iter()
+      .position(|slot| slot.handler.load(Ordering::Relaxed) == 0)
+      .ok_or(EspError::NoMem)?;
+
+    // the seq is published before the handler, so the dispatcher never pairs a handler with a
+    // stale seq
+    let slot = &SLOTS[index];
+    slot
+      .seq
+      .store(NEXT_SEQ.fetch_add(1, Ordering::Relaxed), Ordering::Relaxed);
+    slot.handler.store(handler, Ordering::Release);
+    Ok(ShutdownHandler { slot: index })
+  })
+}
@@ -0,0 +1,135 @@
+use core::{fmt, ptr};
+
+use esp_idf_efuse_sys as efuse_sys;
+use esp_idf_esp32_sys as esp32_sys;
+use esp_idf_spi_flash_sys as flash_sys;
+
+use crate::{esp_result, EspChipInfo, EspError};
+
+/// ESP32 chip package, as recorded in efuse
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChipPackage {
+  /// ESP32-D0WDQ6
+  D0WDQ6,
+  /// ESP32-D0WDQ5
+  D0WDQ5,
+  /// ESP32-D2WDQ5
+  D2WDQ5,
+  /// ESP32-PICO-D2
+  PicoD2,
+  /// ESP32-PICO-D4
+  PicoD4,
+  /// ESP32-PICO-V3-02
+  PicoV302,
+  /// Others, with the raw efuse value
+  Unknown(u32),
+}
+
+impl ChipPackage {
+  /// Read the chip package from efuse
+  pub fn get() -> Self {
+    Self::from_raw(unsafe { efuse_sys::esp_efuse_get_pkg_ver() })
+  }
+
+  fn from_raw(raw: u32) -> Self {
+    match raw {
+      efuse_sys::EFUSE_RD_CHIP_VER_PKG_ESP32D0WDQ6 => Self::D0WDQ6,
+      efuse_sys::EFUSE_RD_CHIP_VER_PKG_ESP32D0WDQ5 => Self::D0WDQ5,
+      efuse_sys::EFUSE_RD_CHIP_VER_PKG_ESP32D2WDQ5 => Self::D2WDQ5,
+      efuse_sys::EFUSE_RD_CHIP_VER_PKG_ESP32PICOD2 => Self::PicoD2,
+      efuse_sys::EFUSE_RD_CHIP_VER_PKG_ESP32PICOD4 => Self::PicoD4,
+      efuse_sys::EFUSE_RD_CHIP_VER_PKG_ESP32PICOV302 => Self::PicoV302,
+      raw => Self::Unknown(raw),
+    }
+  }
+}
+
+impl fmt::Display for ChipPackage {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::D0WDQ6 => f.write_str("D0WDQ6"),
+      Self::D0WDQ5 => f.write_str("D0WDQ5"),
+      Self::D2WDQ5 => f.write_str("D2WDQ5"),
+      Self::PicoD2 => f.write_str("PICO-D2"),
+      Self::PicoD4 => f.write_str("PICO-D4"),
+      Self::PicoV302 => f.write_str("PICO-V3-02"),
+      Self::Unknown(raw) => write!(f, "unknown package ({})", raw),
+    }
+  }
+}
+
+/// Read the flash ID of the default flash chip using the "RDID" SPI flash command
+///
+/// The ID is a 24-bit value. The lower 16 bits are the chip ID, the upper 8 bits are the
+/// manufacturer ID.
+pub fn flash_id() -> Result<u32, EspError> {
+  let mut id = 0;
+  // a NULL chip selects the default flash chip
+  esp_result(unsafe { flash_sys::esp_flash_read_id(ptr::null_mut(), &mut id) })?;
+  Ok(id)
+}
+
+/// Get flash chip size in bytes, as set in the binary image header
+///
+/// This value does not necessarily match the real flash size.
+pub fn flash_size() -> usize {
+  unsafe { flash_sys::spi_flash_get_chip_size() }
+}
+
+/// Get the current CPU clock frequency in Hz
+pub fn cpu_freq_hz() -> u32 {
+  unsafe { esp32_sys::esp_clk_cpu_freq() as u32 }
+}
+
+/// Information about the chip, its package and flash, e.g. for inventory reporting
+///
+/// Displays as a human-readable summary, e.g.
+/// `ESP32 rev 1, 2 cores, WiFi/BT/BLE, package D0WDQ6, 240 MHz, 4 MB flash (id 0x1640ef)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SystemInfo {
+  /// chip model, features and revision
+  pub chip: EspChipInfo,
+  /// chip package
+  pub package: ChipPackage,
+  /// chip revision, as recorded in efuse
+  pub efuse_revision: u8,
+  /// CPU frequency in Hz
+  pub cpu_freq_hz: u32,
+  /// flash chip size in bytes, as set in the binary image header
+  pub flash_size: usize,
+  /// flash chip ID, `None` if it could not be read
+  pub flash_id: Option<u32>,
+}
+
+impl SystemInfo {
+  pub fn get() -> Self {
+    SystemInfo {
+      chip: EspChipInfo::get(),
+      package: ChipPackage::get(),
+      efuse_revision: unsafe { efuse_sys::esp_efuse_get_chip_ver() },
+      cpu_freq_hz: cpu_freq_hz(),
+      flash_size: flash_size(),
+      flash_id: flash_id().ok(),
+    }
+  }
+}
+
+impl fmt::Display for SystemInfo {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "{}, package {}, {} MHz, {} MB flash",
+      self.chip,
+      self.package,
+      self.cpu_freq_hz / 1_000_000,
+      self.flash_size / (1024 * 1024)
+    )?;
+
+    if let Some(id) = self.flash_id {
+      write!(f, " (id {:#08x})", id)?;
+    }
+
+    Ok(())
+  }
+}
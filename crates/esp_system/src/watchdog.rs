@@ -0,0 +1,154 @@
+//! Task watchdog timer (TWDT)
+//!
+//! The TWDT resets the chip (or panics) when a subscribed task does not feed it within the
+//! timeout. With the default sdkconfig it is started by IDF with a 5 second timeout and watches
+//! the idle tasks of both cores.
+//!
+//! ```ignore
+//! TaskWatchdog::init(Duration::from_secs(10), true)?;
+//! let wdt = TaskWatchdog::subscribe_current_task()?;
+//! loop {
+//!   do_work();
+//!   wdt.feed()?;
+//! }
+//! ```
+
+use core::{marker::PhantomData, ptr, time::Duration};
+
+use esp_idf_esp_common_sys as sys;
+use esp_idf_freertos_sys as freertos_sys;
+
+use crate::{esp_result, Core, EspError};
+
+/// The task watchdog timer
+pub struct TaskWatchdog {
+  _private: (),
+}
+
+impl TaskWatchdog {
+  /// Initialize the task watchdog, or reconfigure it if it is already running
+  ///
+  /// The timeout is rounded up to whole seconds. If `panic` is `true` a timeout runs the panic
+  /// handler, otherwise only the tasks that did not feed the watchdog are printed.
+  pub fn init(timeout: Duration, panic: bool) -> Result<(), EspError> {
+    let mut secs = timeout.as_secs();
+    if timeout.subsec_nanos() != 0 {
+      secs += 1;
+    }
+
+    if secs == 0 || secs > u32::MAX as u64 {
+      return Err(EspError::InvalidArg);
+    }
+
+    esp_result(unsafe { sys::esp_task_wdt_init(secs as u32, panic) })
+  }
+
+  /// Change the timeout and panic behavior of the running task watchdog
+  ///
+  /// Returns [EspError::InvalidState] if the task watchdog is not initialized.
+  pub fn reconfigure(timeout: Duration, panic: bool) -> Result<(), EspError> {
+    if !Self::is_initialized() {
+      return Err(EspError::InvalidState);
+    }
+
+    Self::init(timeout, panic)
+  }
+
+  /// Stop the task watchdog
+  ///
+  /// Returns [EspError::InvalidState] if tasks (including idle tasks) are still subscribed.
+  pub fn deinit() -> Result<(), EspError> {
+    esp_result(unsafe { sys::esp_task_wdt_deinit() })
+  }
+
+  /// Returns `true` if the task watchdog is initialized
+  pub fn is_initialized() -> bool {
+    // querying a task that is not subscribed returns NOT_FOUND, unless the TWDT is not running
+    let status = esp_result(unsafe { sys::esp_task_wdt_status(ptr::null_mut()) });
+    status != Err(EspError::InvalidState)
+  }
+
+  /// Subscribe the current task to the task watchdog
+  ///
+  /// The task must call [TaskWatchdogSubscription::feed] within the timeout, until the
+  /// subscription is dropped. Returns [EspError::InvalidArg] if the task is already subscribed
+  /// and [EspError::InvalidState] if the task watchdog is not initialized.
+  pub fn subscribe_current_task() -> Result<TaskWatchdogSubscription, EspError> {
+    let task = unsafe { freertos_sys::xTaskGetCurrentTaskHandle() };
+    esp_result(unsafe { sys::esp_task_wdt_add(task as _) })?;
+    Ok(TaskWatchdogSubscription {
+      task: task as _,
+      _not_send: PhantomData,
+    })
+  }
+
+  /// Enable or disable watching the idle task of `core`
+  ///
+  /// A watched idle task makes the task watchdog fire when a task starves the core. Returns
+  /// [EspError::InvalidArg] if the core does not exist, as [Core::App] with
+  /// `CONFIG_FREERTOS_UNICORE`.
+  pub fn set_idle_task_monitoring(core: Core, enabled: bool) -> Result<(), EspError> {
+    let task = idle_task(core)?;
+    if Self::subscribed(task)? == enabled {
+      return Ok(());
+    }
+
+    if enabled {
+      esp_result(unsafe { sys::esp_task_wdt_add(task) })
+    } else {
+      esp_result(unsafe { sys::esp_task_wdt_delete(task) })
+    }
+  }
+
+  /// Returns `true` if the idle task of `core` is watched
+  ///
+  /// Returns [EspError::InvalidArg] if the core does not exist.
+  pub fn is_idle_task_monitored(core: Core) -> Result<bool, EspError> {
+    Self::subscribed(idle_task(core)?)
+  }
+
+  fn subscribed(task: sys::TaskHandle_t) -> Result<bool, EspError> {
+    match esp_result(unsafe { sys::esp_task_wdt_status(task) }) {
+      Ok(()) => Ok(true),
+      Err(EspError::NotFound) => Ok(false),
+      Err(e) => Err(e),
+    }
+  }
+}
+
+// A NULL handle would make the esp_task_wdt functions act on the calling task instead
+fn idle_task(core: Core) -> Result<sys::TaskHandle_t, EspError> {
+  if core.id() >= freertos_sys::portNUM_PROCESSORS as u32 {
+    return Err(EspError::InvalidArg);
+  }
+
+  let task = unsafe { freertos_sys::xTaskGetIdleTaskHandleForCPU(core.id()) };
+  if task.is_null() {
+    return Err(EspError::InvalidArg);
+  }
+
+  Ok(task as _)
+}
+
+/// Subscription of a task to the task watchdog, unsubscribes the task when dropped
+///
+/// Feeding always happens on behalf of the calling task, so the subscription can not be sent to
+/// other tasks.
+#[must_use = "the task is unsubscribed when the subscription is dropped"]
+pub struct TaskWatchdogSubscription {
+  task: sys::TaskHandle_t,
+  _not_send: PhantomData<*const ()>,
+}
+
+impl TaskWatchdogSubscription {
+  /// Feed the task watchdog on behalf of the subscribed task
+  pub fn feed(&self) -> Result<(), EspError> {
+    esp_result(unsafe { sys::esp_task_wdt_reset() })
+  }
+}
+
+impl Drop for TaskWatchdogSubscription {
+  fn drop(&mut self) {
+    unsafe { sys::esp_task_wdt_delete(self.task) };
+  }
+}
@@ -0,0 +1,80 @@
+use core::time::Duration;
+
+use esp_idf_freertos_sys as freertos_sys;
+
+use crate::Instant;
+
+const TICK_PERIOD_US: u64 = 1_000_000 / freertos_sys::configTICK_RATE_HZ as u64;
+
+/// Blocking delays on the `esp_timer` clock
+///
+/// Delays spanning more than one FreeRTOS tick block the calling task with `vTaskDelay`, so
+/// other tasks can run, and busy-wait only for the remainder. Shorter delays busy-wait. Must not
+/// be used from an ISR, or before the scheduler has started, for delays longer than one tick.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Delay {
+  _private: (),
+}
+
+impl Delay {
+  pub fn new() -> Self {
+    Delay { _private: () }
+  }
+
+  /// Block for `duration`
+  pub fn delay(&self, duration: Duration) {
+    let start = Instant::now();
+    let deadline = match start.checked_add(duration) {
+      Some(deadline) => deadline,
+      None => Instant::from_micros(u64::MAX),
+    };
+
+    // vTaskDelay(n) returns somewhere between n - 1 and n ticks later
+    let ticks = (deadline - start).as_micros() as u64 / TICK_PERIOD_US;
+    if ticks > 1 {
+      let ticks = (ticks - 1).min(freertos_sys::TickType_t::MAX as u64);
+      unsafe { freertos_sys::vTaskDelay(ticks as freertos_sys::TickType_t) };
+    }
+
+    while Instant::now() < deadline {
+      core::hint::spin_loop();
+    }
+  }
+
+  /// Block for `us` microseconds
+  pub fn delay_us(&self, us: u32) {
+    self.delay(Duration::from_micros(us as u64))
+  }
+
+  /// Block for `ms` milliseconds
+  pub fn delay_ms(&self, ms: u32) {
+    self.delay(Duration::from_millis(ms as u64))
+  }
+}
+
+#[cfg(feature = "embedded-hal")]
+mod hal {
+  use embedded_hal::blocking::delay::{DelayMs, DelayUs};
+
+  use super::Delay;
+
+  macro_rules! impl_delay {
+    ($($ty:ty),*) => {
+      $(
+        impl DelayUs<$ty> for Delay {
+          fn delay_us(&mut self, us: $ty) {
+            Delay::delay_us(self, us as u32)
+          }
+        }
+
+        impl DelayMs<$ty> for Delay {
+          fn delay_ms(&mut self, ms: $ty) {
+            Delay::delay_ms(self, ms as u32)
+          }
+        }
+      )*
+    };
+  }
+
+  impl_delay!(u8, u16, u32);
+}
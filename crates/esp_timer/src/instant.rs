@@ -0,0 +1,114 @@
+use core::{
+  fmt,
+  ops::{Add, AddAssign, Sub, SubAssign},
+  time::Duration,
+};
+
+use esp_idf_timer_sys as sys;
+
+/// A measurement of the monotonic `esp_timer` clock, in microseconds since boot
+///
+/// Works like `std::time::Instant`. The clock keeps running in light sleep, but restarts from
+/// zero after deep sleep.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Instant(u64);
+
+impl Instant {
+  /// The current time
+  pub fn now() -> Self {
+    Instant(unsafe { sys::esp_timer_get_time() } as u64)
+  }
+
+  /// Create an instant from a number of microseconds since boot
+  pub const fn from_micros(micros: u64) -> Self {
+    Instant(micros)
+  }
+
+  /// Number of microseconds since boot
+  pub const fn as_micros(&self) -> u64 {
+    self.0
+  }
+
+  /// Time elapsed from `earlier` to `self`, zero if `earlier` is later than `self`
+  pub fn duration_since(&self, earlier: Instant) -> Duration {
+    self.checked_duration_since(earlier).unwrap_or_default()
+  }
+
+  /// Time elapsed from `earlier` to `self`, `None` if `earlier` is later than `self`
+  pub fn checked_duration_since(&self, earlier: Instant) -> Option<Duration> {
+    self.0.checked_sub(earlier.0).map(Duration::from_micros)
+  }
+
+  /// Time elapsed since this instant
+  pub fn elapsed(&self) -> Duration {
+    Self::now().duration_since(*self)
+  }
+
+  /// `self + duration`, `None` on overflow
+  pub fn checked_add(&self, duration: Duration) -> Option<Instant> {
+    to_micros(duration)
+      .and_then(|micros| self.0.checked_add(micros))
+      .map(Instant)
+  }
+
+  /// `self - duration`, `None` if the result would be before boot
+  pub fn checked_sub(&self, duration: Duration) -> Option<Instant> {
+    to_micros(duration)
+      .and_then(|micros| self.0.checked_sub(micros))
+      .map(Instant)
+  }
+}
+
+/// Convert a duration to whole microseconds, `None` if it does not fit in a `u64`
+pub(crate) fn to_micros(duration: Duration) -> Option<u64> {
+  duration
+    .as_secs()
+    .checked_mul(1_000_000)
+    .and_then(|micros| micros.checked_add(duration.subsec_micros() as u64))
+}
+
+impl Add<Duration> for Instant {
+  type Output = Instant;
+
+  fn add(self, duration: Duration) -> Instant {
+    self
+      .checked_add(duration)
+      .expect("overflow when adding duration to instant")
+  }
+}
+
+impl AddAssign<Duration> for Instant {
+  fn add_assign(&mut self, duration: Duration) {
+    *self = *self + duration;
+  }
+}
+
+impl Sub<Duration> for Instant {
+  type Output = Instant;
+
+  fn sub(self, duration: Duration) -> Instant {
+    self
+      .checked_sub(duration)
+      .expect("overflow when subtracting duration from instant")
+  }
+}
+
+impl SubAssign<Duration> for Instant {
+  fn sub_assign(&mut self, duration: Duration) {
+    *self = *self - duration;
+  }
+}
+
+impl Sub<Instant> for Instant {
+  type Output = Duration;
+
+  fn sub(self, earlier: Instant) -> Duration {
+    self.duration_since(earlier)
+  }
+}
+
+impl fmt::Debug for Instant {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt::Debug::fmt(&Duration::from_micros(self.0), f)
+  }
+}
@@ -0,0 +1,16 @@
+//! High resolution timers based on `esp_timer`.
+//!
+//! * [Instant] - monotonic clock with microsecond resolution, counting from boot
+//! * [Timer] - one-shot and periodic callbacks
+//! * [Delay] - blocking delays, implementing the `embedded-hal` delay traits with the
+//!   `embedded-hal` feature
+
+#![no_std]
+
+mod delay;
+mod instant;
+mod timer;
+
+pub use delay::*;
+pub use instant::*;
+pub use timer::*;
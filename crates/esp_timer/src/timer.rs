@@ -0,0 +1,255 @@
+use core::{
+  cell::UnsafeCell,
+  ffi::c_void,
+  ptr,
+  sync::atomic::{AtomicPtr, AtomicU8, Ordering},
+  time::Duration,
+};
+
+use esp_idf_freertos_sys as freertos_sys;
+use esp_idf_system::{esp_result, heap::CapsBox, heap::HeapCaps, EspError};
+use esp_idf_timer_sys as sys;
+
+use crate::instant::to_micros;
+
+const IDLE: u8 = 0;
+const RUNNING: u8 = 1;
+const DROPPED: u8 = 2;
+
+// Header shared by all states, so it can be handled without knowing F
+struct StateHeader {
+  state: AtomicU8,
+  // link in the list of states waiting to be freed by the reaper
+  next: *mut StateHeader,
+  release: unsafe fn(*mut StateHeader),
+}
+
+#[repr(C)]
+struct TimerState<F> {
+  // first field, so the state can be used as a `StateHeader`
+  header: StateHeader,
+  callback: UnsafeCell<F>,
+}
+
+// The esp_timer task, recorded by `dispatch` so a timer dropped from a callback can be detected
+static TIMER_TASK: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+
+unsafe extern "C" fn dispatch<F: FnMut() + Send + 'static>(arg: *mut c_void) {
+  TIMER_TASK.store(
+    freertos_sys::xTaskGetCurrentTaskHandle() as *mut c_void,
+    Ordering::Relaxed,
+  );
+
+  let state = &*(arg as *const TimerState<F>);
+  if state
+    .header
+    .state
+    .compare_exchange(IDLE, RUNNING, Ordering::Acquire, Ordering::Relaxed)
+    .is_err()
+  {
+    // the timer was dropped after this dispatch was picked up
+    return;
+  }
+
+  (*state.callback.get())();
+  // fails if the callback dropped its own timer, the state then stays DROPPED. It is only freed
+  // by the reaper, which runs after this dispatch returns.
+  let _ = state
+    .header
+    .state
+    .compare_exchange(RUNNING, IDLE, Ordering::Release, Ordering::Relaxed);
+}
+
+unsafe fn release_state<F>(state: *mut StateHeader) {
+  drop(CapsBox::from_raw(state as *mut TimerState<F>));
+}
+
+// The esp_timer task reads the callback and its argument before calling `dispatch`, so a timer
+// that has been stopped and deleted can still have one dispatch in flight. Its state can only be
+// freed once that dispatch is done. Dropped states are handed to a one-shot "reaper" timer
+// instead: it runs in the same task as every dispatch, so by the time it runs no earlier
+// dispatch can still reach them.
+static REAPER: AtomicPtr<sys::esp_timer> = AtomicPtr::new(ptr::null_mut());
+static PENDING: AtomicPtr<StateHeader> = AtomicPtr::new(ptr::null_mut());
+
+unsafe extern "C" fn reap(_arg: *mut c_void) {
+  let mut state = PENDING.swap(ptr::null_mut(), Ordering::Acquire);
+  while !state.is_null() {
+    let next = (*state).next;
+    ((*state).release)(state);
+    state = next;
+  }
+}
+
+fn reaper() -> Result<sys::esp_timer_handle_t, EspError> {
+  let reaper = REAPER.load(Ordering::Acquire);
+  if !reaper.is_null() {
+    return Ok(reaper);
+  }
+
+  let args = sys::esp_timer_create_args_t {
+    callback: Some(reap),
+    arg: ptr::null_mut(),
+    dispatch_method: sys::esp_timer_dispatch_t_ESP_TIMER_TASK,
+    name: b"timer_reaper\0".as_ptr() as *const _,
+  };
+
+  let mut created = ptr::null_mut();
+  esp_result(unsafe { sys::esp_timer_create(&args, &mut created) })?;
+  match REAPER.compare_exchange(
+    ptr::null_mut(),
+    created,
+    Ordering::AcqRel,
+    Ordering::Acquire,
+  ) {
+    Ok(_) => Ok(created),
+    Err(existing) => {
+      // another task created the reaper first
+      unsafe { sys::esp_timer_delete(created) };
+      Ok(existing)
+    }
+  }
+}
+
+/// A timer calling a closure once or periodically
+///
+/// Callbacks are dispatched from the `esp_timer` task, one at a time, so they should be short and
+/// hand longer work off to other tasks. Dispatching callbacks from the timer ISR
+/// (`ESP_TIMER_ISR`) is not offered, as it is not available in IDF 4.2.
+///
+/// Dropping the timer stops and deletes it. When dropped from another task, this waits for a
+/// running callback to finish. A timer may also be dropped from its own callback, the closure is
+/// then freed after the callback returns.
+///
+/// ```ignore
+/// let blink = Timer::periodic(Duration::from_millis(500), move || led.toggle())?;
+/// ```
+pub struct Timer {
+  handle: sys::esp_timer_handle_t,
+  state: *mut StateHeader,
+}
+
+unsafe impl Send for Timer {}
+unsafe impl Sync for Timer {}
+
+impl Timer {
+  /// Create a stopped timer calling `callback` when it fires
+  pub fn new<F>(callback: F) -> Result<Timer, EspError>
+  where
+    F: FnMut() + Send + 'static,
+  {
+    // created up front, so dropping the timer can not fail
+    reaper()?;
+
+    let state = TimerState {
+      header: StateHeader {
+        state: AtomicU8::new(IDLE),
+        next: ptr::null_mut(),
+        release: release_state::<F>,
+      },
+      callback: UnsafeCell::new(callback),
+    };
+    let state = CapsBox::into_raw(CapsBox::new_in(state, HeapCaps::DEFAULT)?) as *mut StateHeader;
+
+    let args = sys::esp_timer_create_args_t {
+      callback: Some(dispatch::<F>),
+      arg: state as *mut c_void,
+      dispatch_method: sys::esp_timer_dispatch_t_ESP_TIMER_TASK,
+      name: ptr::null(),
+    };
+
+    let mut handle = ptr::null_mut();
+    if let Err(e) = esp_result(unsafe { sys::esp_timer_create(&args, &mut handle) }) {
+      unsafe { release_state::<F>(state) };
+      return Err(e);
+    }
+
+    Ok(Timer { handle, state })
+  }
+
+  /// Create a timer calling `callback` once, after `timeout`
+  pub fn one_shot<F>(timeout: Duration, callback: F) -> Result<Timer, EspError>
+  where
+    F: FnMut() + Send + 'static,
+  {
+    let timer = Self::new(callback)?;
+    timer.start_once(timeout)?;
+    Ok(timer)
+  }
+
+  /// Create a timer calling `callback` every `period`
+  pub fn periodic<F>(period: Duration, callback: F) -> Result<Timer, EspError>
+  where
+    F: FnMut() + Send + 'static,
+  {
+    let timer = Self::new(callback)?;
+    timer.start_periodic(period)?;
+    Ok(timer)
+  }
+
+  /// Fire once, after `timeout`
+  ///
+  /// Returns [EspError::InvalidState] if the timer is already running.
+  pub fn start_once(&self, timeout: Duration) -> Result<(), EspError> {
+    let timeout = to_micros(timeout).ok_or(EspError::InvalidArg)?;
+    esp_result(unsafe { sys::esp_timer_start_once(self.handle, timeout) })
+  }
+
+  /// Fire every `period`, starting `period` from now
+  ///
+  /// Returns [EspError::InvalidState] if the timer is already running.
+  pub fn start_periodic(&self, period: Duration) -> Result<(), EspError> {
+    let period = to_micros(period).ok_or(EspError::InvalidArg)?;
+    esp_result(unsafe { sys::esp_timer_start_periodic(self.handle, period) })
+  }
+
+  /// Stop the timer
+  ///
+  /// Returns [EspError::InvalidState] if the timer is not running. A callback that is already
+  /// running is not interrupted.
+  pub fn stop(&self) -> Result<(), EspError> {
+    esp_result(unsafe { sys::esp_timer_stop(self.handle) })
+  }
+}
+
+impl Drop for Timer {
+  fn drop(&mut self) {
+    unsafe {
+      sys::esp_timer_stop(self.handle);
+      sys::esp_timer_delete(self.handle);
+
+      // keep dispatches still in flight from calling the callback, and wait for a running one
+      let state = &*self.state;
+      let current = freertos_sys::xTaskGetCurrentTaskHandle() as *mut c_void;
+      loop {
+        match state
+          .state
+          .compare_exchange(IDLE, DROPPED, Ordering::Acquire, Ordering::Relaxed)
+        {
+          Ok(_) => break,
+          // callbacks only run in the esp_timer task, so this is our own callback. It can not be
+          // waited for, `dispatch` leaves the state alone once it sees DROPPED.
+          Err(RUNNING) if TIMER_TASK.load(Ordering::Relaxed) == current => {
+            state.state.store(DROPPED, Ordering::Relaxed);
+            break;
+          }
+          // sleep rather than spin, the callback may need this core to finish
+          Err(_) => freertos_sys::vTaskDelay(1),
+        }
+      }
+
+      let mut head = PENDING.load(Ordering::Relaxed);
+      loop {
+        (*self.state).next = head;
+        match PENDING.compare_exchange_weak(head, self.state, Ordering::Release, Ordering::Relaxed)
+        {
+          Ok(_) => break,
+          Err(current) => head = current,
+        }
+      }
+
+      // fails with ESP_ERR_INVALID_STATE if the reaper is already armed, it frees this state too
+      sys::esp_timer_start_once(REAPER.load(Ordering::Acquire), 0);
+    }
+  }
+}
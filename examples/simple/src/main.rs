@@ -1,7 +1,7 @@
 #![no_std]
 #![no_main]
 
-extern crate panic_halt;
+extern crate esp_idf_panic;
 extern crate xtensa_lx_rt;
 
 #[xtensa_lx_rt::entry]
@@ -0,0 +1,35 @@
+/* automatically generated by rust-bindgen 0.56.0 */
+
+#![allow(non_camel_case_types, non_upper_case_globals)]
+
+extern "C" {
+  #[doc = " @brief Enable an entropy source for RNG if RF is disabled"]
+  #[doc = ""]
+  #[doc = " The exact internal entropy source mechanism depends on the chip in use but"]
+  #[doc = " all SoCs use the SAR ADC to continuously mix random bits (an internal"]
+  #[doc = " noise reading) into the HWRNG. Consult the SoC Technical Reference"]
+  #[doc = " Manual for more information."]
+  #[doc = ""]
+  #[doc = " Can also be called from app code, if true random numbers are required"]
+  #[doc = " without initialized RF subsystem. This might be the case in early startup"]
+  #[doc = " code of the application when the RF subsystem has not started yet or if"]
+  #[doc = " the RF subsystem should not be enabled for power saving."]
+  #[doc = ""]
+  #[doc = " Consult ESP-IDF Programming Guide \"Random Number Generation\" section for"]
+  #[doc = " details."]
+  #[doc = ""]
+  #[doc = " @warning This function is not safe to use if any other subsystem is accessing"]
+  #[doc = " the RF subsystem or the ADC at the same time!"]
+  pub fn bootloader_random_enable();
+}
+extern "C" {
+  #[doc = " @brief Disable entropy source for RNG"]
+  #[doc = ""]
+  #[doc = " Disables internal entropy source. Must be called after"]
+  #[doc = " bootloader_random_enable() and before RF subsystem features, ADC, or"]
+  #[doc = " I2S (ESP32 only) are initialized."]
+  #[doc = ""]
+  #[doc = " Consult the ESP-IDF Programming Guide \"Random Number Generation\""]
+  #[doc = " section for details."]
+  pub fn bootloader_random_disable();
+}
@@ -0,0 +1,5 @@
+#![no_std]
+
+mod bindings;
+
+pub use bindings::*;
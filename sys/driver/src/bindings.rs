@@ -0,0 +1,113 @@
+/* automatically generated by rust-bindgen 0.56.0 */
+
+#![allow(non_camel_case_types, non_upper_case_globals)]
+
+pub type esp_err_t = i32;
+#[doc = "< Use to signal not connected to S/W"]
+pub const gpio_num_t_GPIO_NUM_NC: gpio_num_t = -1;
+#[doc = "< GPIO0, input and output"]
+pub const gpio_num_t_GPIO_NUM_0: gpio_num_t = 0;
+#[doc = "< GPIO1, input and output"]
+pub const gpio_num_t_GPIO_NUM_1: gpio_num_t = 1;
+#[doc = "< GPIO2, input and output"]
+pub const gpio_num_t_GPIO_NUM_2: gpio_num_t = 2;
+#[doc = "< GPIO3, input and output"]
+pub const gpio_num_t_GPIO_NUM_3: gpio_num_t = 3;
+#[doc = "< GPIO4, input and output"]
+pub const gpio_num_t_GPIO_NUM_4: gpio_num_t = 4;
+#[doc = "< GPIO5, input and output"]
+pub const gpio_num_t_GPIO_NUM_5: gpio_num_t = 5;
+#[doc = "< GPIO6, input and output"]
+pub const gpio_num_t_GPIO_NUM_6: gpio_num_t = 6;
+#[doc = "< GPIO7, input and output"]
+pub const gpio_num_t_GPIO_NUM_7: gpio_num_t = 7;
+#[doc = "< GPIO8, input and output"]
+pub const gpio_num_t_GPIO_NUM_8: gpio_num_t = 8;
+#[doc = "< GPIO9, input and output"]
+pub const gpio_num_t_GPIO_NUM_9: gpio_num_t = 9;
+#[doc = "< GPIO10, input and output"]
+pub const gpio_num_t_GPIO_NUM_10: gpio_num_t = 10;
+#[doc = "< GPIO11, input and output"]
+pub const gpio_num_t_GPIO_NUM_11: gpio_num_t = 11;
+#[doc = "< GPIO12, input and output"]
+pub const gpio_num_t_GPIO_NUM_12: gpio_num_t = 12;
+#[doc = "< GPIO13, input and output"]
+pub const gpio_num_t_GPIO_NUM_13: gpio_num_t = 13;
+#[doc = "< GPIO14, input and output"]
+pub const gpio_num_t_GPIO_NUM_14: gpio_num_t = 14;
+#[doc = "< GPIO15, input and output"]
+pub const gpio_num_t_GPIO_NUM_15: gpio_num_t = 15;
+#[doc = "< GPIO16, input and output"]
+pub const gpio_num_t_GPIO_NUM_16: gpio_num_t = 16;
+#[doc = "< GPIO17, input and output"]
+pub const gpio_num_t_GPIO_NUM_17: gpio_num_t = 17;
+#[doc = "< GPIO18, input and output"]
+pub const gpio_num_t_GPIO_NUM_18: gpio_num_t = 18;
+#[doc = "< GPIO19, input and output"]
+pub const gpio_num_t_GPIO_NUM_19: gpio_num_t = 19;
+#[doc = "< GPIO21, input and output"]
+pub const gpio_num_t_GPIO_NUM_21: gpio_num_t = 21;
+#[doc = "< GPIO22, input and output"]
+pub const gpio_num_t_GPIO_NUM_22: gpio_num_t = 22;
+#[doc = "< GPIO23, input and output"]
+pub const gpio_num_t_GPIO_NUM_23: gpio_num_t = 23;
+#[doc = "< GPIO25, input and output"]
+pub const gpio_num_t_GPIO_NUM_25: gpio_num_t = 25;
+#[doc = "< GPIO26, input and output"]
+pub const gpio_num_t_GPIO_NUM_26: gpio_num_t = 26;
+#[doc = "< GPIO27, input and output"]
+pub const gpio_num_t_GPIO_NUM_27: gpio_num_t = 27;
+#[doc = "< GPIO32, input and output"]
+pub const gpio_num_t_GPIO_NUM_32: gpio_num_t = 32;
+#[doc = "< GPIO33, input and output"]
+pub const gpio_num_t_GPIO_NUM_33: gpio_num_t = 33;
+#[doc = "< GPIO34, input mode only"]
+pub const gpio_num_t_GPIO_NUM_34: gpio_num_t = 34;
+#[doc = "< GPIO35, input mode only"]
+pub const gpio_num_t_GPIO_NUM_35: gpio_num_t = 35;
+#[doc = "< GPIO36, input mode only"]
+pub const gpio_num_t_GPIO_NUM_36: gpio_num_t = 36;
+#[doc = "< GPIO37, input mode only"]
+pub const gpio_num_t_GPIO_NUM_37: gpio_num_t = 37;
+#[doc = "< GPIO38, input mode only"]
+pub const gpio_num_t_GPIO_NUM_38: gpio_num_t = 38;
+#[doc = "< GPIO39, input mode only"]
+pub const gpio_num_t_GPIO_NUM_39: gpio_num_t = 39;
+#[doc = "< GPIO pin number"]
+pub const gpio_num_t_GPIO_NUM_MAX: gpio_num_t = 40;
+pub type gpio_num_t = ::cty::c_int;
+pub const gpio_int_type_t_GPIO_INTR_DISABLE: gpio_int_type_t = 0;
+#[doc = "< GPIO interrupt type : rising edge"]
+pub const gpio_int_type_t_GPIO_INTR_POSEDGE: gpio_int_type_t = 1;
+#[doc = "< GPIO interrupt type : falling edge"]
+pub const gpio_int_type_t_GPIO_INTR_NEGEDGE: gpio_int_type_t = 2;
+#[doc = "< GPIO interrupt type : both rising and falling edge"]
+pub const gpio_int_type_t_GPIO_INTR_ANYEDGE: gpio_int_type_t = 3;
+#[doc = "< GPIO interrupt type : input low level trigger"]
+pub const gpio_int_type_t_GPIO_INTR_LOW_LEVEL: gpio_int_type_t = 4;
+#[doc = "< GPIO interrupt type : input high level trigger"]
+pub const gpio_int_type_t_GPIO_INTR_HIGH_LEVEL: gpio_int_type_t = 5;
+pub const gpio_int_type_t_GPIO_INTR_MAX: gpio_int_type_t = 6;
+pub type gpio_int_type_t = ::cty::c_uint;
+extern "C" {
+  #[doc = " @brief Enable GPIO wake-up function."]
+  #[doc = ""]
+  #[doc = " @param gpio_num GPIO number."]
+  #[doc = ""]
+  #[doc = " @param intr_type GPIO wake-up type. Only GPIO_INTR_LOW_LEVEL or GPIO_INTR_HIGH_LEVEL can be used."]
+  #[doc = ""]
+  #[doc = " @return"]
+  #[doc = "     - ESP_OK Success"]
+  #[doc = "     - ESP_ERR_INVALID_ARG Parameter error"]
+  pub fn gpio_wakeup_enable(gpio_num: gpio_num_t, intr_type: gpio_int_type_t) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief Disable GPIO wake-up function."]
+  #[doc = ""]
+  #[doc = " @param gpio_num GPIO number"]
+  #[doc = ""]
+  #[doc = " @return"]
+  #[doc = "     - ESP_OK Success"]
+  #[doc = "     - ESP_ERR_INVALID_ARG Parameter error"]
+  pub fn gpio_wakeup_disable(gpio_num: gpio_num_t) -> esp_err_t;
+}
@@ -0,0 +1,16 @@
+/* automatically generated by rust-bindgen 0.56.0 */
+
+#![allow(non_camel_case_types, non_upper_case_globals)]
+
+extern "C" {
+  #[doc = " @brief   Returns chip version from efuse"]
+  #[doc = ""]
+  #[doc = " @return chip version"]
+  pub fn esp_efuse_get_chip_ver() -> u8;
+}
+extern "C" {
+  #[doc = " @brief   Returns chip package from efuse"]
+  #[doc = ""]
+  #[doc = " @return chip package"]
+  pub fn esp_efuse_get_pkg_ver() -> u32;
+}
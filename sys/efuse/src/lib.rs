@@ -0,0 +1,13 @@
+#![no_std]
+
+// Package versions (EFUSE_RD_CHIP_VER_PKG), see soc/efuse_reg.h
+pub const EFUSE_RD_CHIP_VER_PKG_ESP32D0WDQ6: u32 = 0;
+pub const EFUSE_RD_CHIP_VER_PKG_ESP32D0WDQ5: u32 = 1;
+pub const EFUSE_RD_CHIP_VER_PKG_ESP32D2WDQ5: u32 = 2;
+pub const EFUSE_RD_CHIP_VER_PKG_ESP32PICOD2: u32 = 4;
+pub const EFUSE_RD_CHIP_VER_PKG_ESP32PICOD4: u32 = 5;
+pub const EFUSE_RD_CHIP_VER_PKG_ESP32PICOV302: u32 = 6;
+
+mod bindings;
+
+pub use bindings::*;
@@ -0,0 +1,13 @@
+/* automatically generated by rust-bindgen 0.56.0 */
+
+#![allow(non_camel_case_types, non_upper_case_globals)]
+
+extern "C" {
+  #[doc = " @brief Return current CPU clock frequency"]
+  #[doc = " When frequency switching is used, this frequency may change."]
+  #[doc = " However it is guaranteed that the frequency never changes with a critical"]
+  #[doc = " section."]
+  #[doc = ""]
+  #[doc = " @return CPU clock frequency, in Hz"]
+  pub fn esp_clk_cpu_freq() -> ::cty::c_int;
+}
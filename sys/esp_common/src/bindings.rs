@@ -0,0 +1,122 @@
+/* automatically generated by rust-bindgen 0.56.0 */
+
+#![allow(non_camel_case_types, non_upper_case_globals)]
+
+pub type esp_err_t = i32;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tskTaskControlBlock {
+  _unused: [u8; 0],
+}
+pub type TaskHandle_t = *mut tskTaskControlBlock;
+extern "C" {
+  #[doc = " @brief  Initialize the Task Watchdog Timer (TWDT)"]
+  #[doc = ""]
+  #[doc = " This function configures and initializes the TWDT. If the TWDT is already"]
+  #[doc = " initialized when this function is called, this function will update the"]
+  #[doc = " TWDT's timeout period and panic configurations instead. After initializing"]
+  #[doc = " the TWDT, any task can elect to be watched by the TWDT by subscribing to it"]
+  #[doc = " using esp_task_wdt_add()."]
+  #[doc = ""]
+  #[doc = " @param[in]  timeout     Timeout period of TWDT in seconds"]
+  #[doc = " @param[in]  panic       Flag that controls whether the panic handler will be"]
+  #[doc = "                         executed when the TWDT times out"]
+  #[doc = ""]
+  #[doc = " @return"]
+  #[doc = "  - ESP_OK: Initialization was successful"]
+  #[doc = "  - ESP_ERR_NO_MEM: Initialization failed due to lack of memory"]
+  #[doc = ""]
+  #[doc = " @note   esp_task_wdt_init() must only be called after the scheduler"]
+  #[doc = "         started"]
+  pub fn esp_task_wdt_init(timeout: u32, panic: bool) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief Deinitialize the Task Watchdog Timer (TWDT)"]
+  #[doc = ""]
+  #[doc = " Attempting to deinitialize the TWDT while tasks are still subscribed to the"]
+  #[doc = " TWDT, or when the TWDT is already deinitialized, will result in an error"]
+  #[doc = " code being returned."]
+  #[doc = ""]
+  #[doc = " @return"]
+  #[doc = "  - ESP_OK: TWDT successfully deinitialized"]
+  #[doc = "  - ESP_ERR_INVALID_STATE: Error, tasks are still subscribed to the TWDT"]
+  #[doc = "  - ESP_ERR_NOT_FOUND: Error, TWDT has already been deinitialized"]
+  pub fn esp_task_wdt_deinit() -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief   Subscribe a task to the Task Watchdog Timer (TWDT)"]
+  #[doc = ""]
+  #[doc = " This function subscribes a task to the TWDT. Each subscribed task must"]
+  #[doc = " periodically call esp_task_wdt_reset() to prevent the TWDT from elapsing its"]
+  #[doc = " timeout period. Failure to do so will result in a TWDT timeout. If the task"]
+  #[doc = " being subscribed is one of the Idle Tasks, this function will automatically"]
+  #[doc = " register esp_task_wdt_reset() as an Idle Hook of the Idle Task."]
+  #[doc = ""]
+  #[doc = " @param[in]   handle  Handle of the task. Input NULL to subscribe the current"]
+  #[doc = "                      running task to the TWDT"]
+  #[doc = ""]
+  #[doc = " @return"]
+  #[doc = "  - ESP_OK: Successfully subscribed the task to the TWDT"]
+  #[doc = "  - ESP_ERR_INVALID_ARG: Error, the task is already subscribed"]
+  #[doc = "  - ESP_ERR_NO_MEM: Error, could not subscribe the task due to lack of"]
+  #[doc = "                    memory"]
+  #[doc = "  - ESP_ERR_INVALID_STATE: Error, the TWDT has not been initialized yet"]
+  pub fn esp_task_wdt_add(handle: TaskHandle_t) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief   Reset the Task Watchdog Timer (TWDT) on behalf of the currently"]
+  #[doc = "          running task"]
+  #[doc = ""]
+  #[doc = " This function will reset the TWDT on behalf of the currently running task."]
+  #[doc = " Each subscribed task must periodically call this function to prevent the"]
+  #[doc = " TWDT from timing out. If one or more subscribed tasks fail to reset the"]
+  #[doc = " TWDT on their own behalf, a TWDT timeout will occur. If the IDLE tasks have"]
+  #[doc = " been subscribed to the TWDT, they will automatically call this function from"]
+  #[doc = " their idle hooks. Calling this function from a task that has not subscribed"]
+  #[doc = " to the TWDT, or when the TWDT is uninitialized will result in an error code"]
+  #[doc = " being returned."]
+  #[doc = ""]
+  #[doc = " @return"]
+  #[doc = "  - ESP_OK: Successfully reset the TWDT on behalf of the currently"]
+  #[doc = "            running task"]
+  #[doc = "  - ESP_ERR_NOT_FOUND: Error, the current running task has not subscribed"]
+  #[doc = "                       to the TWDT"]
+  #[doc = "  - ESP_ERR_INVALID_STATE: Error, the TWDT has not been initialized yet"]
+  pub fn esp_task_wdt_reset() -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief   Unsubscribes a task from the Task Watchdog Timer (TWDT)"]
+  #[doc = ""]
+  #[doc = " This function will unsubscribe a task from the TWDT. After being"]
+  #[doc = " unsubscribed, the task should no longer call esp_task_wdt_reset(). If the"]
+  #[doc = " task is an IDLE task, this function will automatically unregister the"]
+  #[doc = " esp_task_wdt_reset() function from the Idle Hook of the IDLE task. Calling"]
+  #[doc = " this function whilst the TWDT is uninitialized or attempting to unsubscribe"]
+  #[doc = " an already unsubscribed task from the TWDT will result in an error code"]
+  #[doc = " being returned."]
+  #[doc = ""]
+  #[doc = " @param[in] handle    Handle of the task. Input NULL to unsubscribe the"]
+  #[doc = "                      current running task."]
+  #[doc = ""]
+  #[doc = " @return"]
+  #[doc = "  - ESP_OK: Successfully unsubscribed the task from the TWDT"]
+  #[doc = "  - ESP_ERR_INVALID_ARG: Error, the task is already unsubscribed"]
+  #[doc = "  - ESP_ERR_INVALID_STATE: Error, the TWDT has not been initialized yet"]
+  pub fn esp_task_wdt_delete(handle: TaskHandle_t) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief   Query whether a task is subscribed to the Task Watchdog Timer (TWDT)"]
+  #[doc = ""]
+  #[doc = " This function will query whether a task is currently subscribed to the TWDT,"]
+  #[doc = " or whether the TWDT is initialized."]
+  #[doc = ""]
+  #[doc = " @param[in] handle    Handle of the task. Input NULL to query the current"]
+  #[doc = "                      running task."]
+  #[doc = ""]
+  #[doc = " @return:"]
+  #[doc = "  - ESP_OK: The task is currently subscribed to the TWDT"]
+  #[doc = "  - ESP_ERR_NOT_FOUND: The task is currently not subscribed to the TWDT"]
+  #[doc = "  - ESP_ERR_INVALID_STATE: The TWDT is not initialized, therefore no tasks"]
+  #[doc = "                           can be subscribed"]
+  pub fn esp_task_wdt_status(handle: TaskHandle_t) -> esp_err_t;
+}
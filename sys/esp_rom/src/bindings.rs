@@ -0,0 +1,65 @@
+/* automatically generated by rust-bindgen 0.56.0 */
+
+#![allow(non_camel_case_types, non_upper_case_globals)]
+
+pub const RESET_REASON_NO_MEAN: RESET_REASON = 0;
+#[doc = "<1, Vbat power on reset"]
+pub const RESET_REASON_POWERON_RESET: RESET_REASON = 1;
+#[doc = "<3, Software reset digital core"]
+pub const RESET_REASON_SW_RESET: RESET_REASON = 3;
+#[doc = "<4, Legacy watch dog reset digital core"]
+pub const RESET_REASON_OWDT_RESET: RESET_REASON = 4;
+#[doc = "<3, Deep Sleep reset digital core"]
+pub const RESET_REASON_DEEPSLEEP_RESET: RESET_REASON = 5;
+#[doc = "<6, Reset by SLC module, reset digital core"]
+pub const RESET_REASON_SDIO_RESET: RESET_REASON = 6;
+#[doc = "<7, Timer Group0 Watch dog reset digital core"]
+pub const RESET_REASON_TG0WDT_SYS_RESET: RESET_REASON = 7;
+#[doc = "<8, Timer Group1 Watch dog reset digital core"]
+pub const RESET_REASON_TG1WDT_SYS_RESET: RESET_REASON = 8;
+#[doc = "<9, RTC Watch dog Reset digital core"]
+pub const RESET_REASON_RTCWDT_SYS_RESET: RESET_REASON = 9;
+#[doc = "<10, Instrusion tested to reset CPU"]
+pub const RESET_REASON_INTRUSION_RESET: RESET_REASON = 10;
+#[doc = "<11, Time Group reset CPU"]
+pub const RESET_REASON_TGWDT_CPU_RESET: RESET_REASON = 11;
+#[doc = "<12, Software reset CPU"]
+pub const RESET_REASON_SW_CPU_RESET: RESET_REASON = 12;
+#[doc = "<13, RTC Watch dog Reset CPU"]
+pub const RESET_REASON_RTCWDT_CPU_RESET: RESET_REASON = 13;
+#[doc = "<14, for APP CPU, reseted by PRO CPU"]
+pub const RESET_REASON_EXT_CPU_RESET: RESET_REASON = 14;
+#[doc = "<15, Reset when the vdd voltage is not stable"]
+pub const RESET_REASON_RTCWDT_BROWN_OUT_RESET: RESET_REASON = 15;
+#[doc = "<16, RTC Watch dog reset digital core and rtc module"]
+pub const RESET_REASON_RTCWDT_RTC_RESET: RESET_REASON = 16;
+pub type RESET_REASON = ::cty::c_uint;
+pub const WAKEUP_REASON_NO_SLEEP: WAKEUP_REASON = 0;
+pub const WAKEUP_REASON_EXT_EVENT0_TRIG: WAKEUP_REASON = 1;
+pub const WAKEUP_REASON_EXT_EVENT1_TRIG: WAKEUP_REASON = 2;
+pub const WAKEUP_REASON_GPIO_TRIG: WAKEUP_REASON = 4;
+pub const WAKEUP_REASON_TIMER_EXPIRE: WAKEUP_REASON = 8;
+pub const WAKEUP_REASON_SDIO_TRIG: WAKEUP_REASON = 16;
+pub const WAKEUP_REASON_MAC_TRIG: WAKEUP_REASON = 32;
+pub const WAKEUP_REASON_UART0_TRIG: WAKEUP_REASON = 64;
+pub const WAKEUP_REASON_UART1_TRIG: WAKEUP_REASON = 128;
+pub const WAKEUP_REASON_TOUCH_TRIG: WAKEUP_REASON = 256;
+pub const WAKEUP_REASON_SAR_TRIG: WAKEUP_REASON = 512;
+pub const WAKEUP_REASON_BT_TRIG: WAKEUP_REASON = 1024;
+pub type WAKEUP_REASON = ::cty::c_uint;
+extern "C" {
+  #[doc = " @brief  Get the reset reason for CPU."]
+  #[doc = ""]
+  #[doc = " @param  int cpu_no : CPU no."]
+  #[doc = ""]
+  #[doc = " @return RESET_REASON"]
+  pub fn rtc_get_reset_reason(cpu_no: ::cty::c_int) -> RESET_REASON;
+}
+extern "C" {
+  #[doc = " @brief  Get the wakeup cause for CPU."]
+  #[doc = ""]
+  #[doc = " @param  int cpu_no : CPU no."]
+  #[doc = ""]
+  #[doc = " @return WAKEUP_REASON"]
+  pub fn rtc_get_wakeup_cause() -> WAKEUP_REASON;
+}
@@ -6,6 +6,20 @@ pub type __uint8_t = ::cty::c_uchar;
 pub type __int32_t = ::cty::c_int;
 pub type __uint32_t = ::cty::c_uint;
 pub type esp_err_t = i32;
+extern "C" {
+  #[doc = " @brief Returns string for esp_err_t error codes"]
+  #[doc = ""]
+  #[doc = " This function finds the error code in a pre-generated lookup-table and"]
+  #[doc = " returns its string representation."]
+  #[doc = ""]
+  #[doc = " The function is generated by the Python script"]
+  #[doc = " tools/gen_esp_err_to_name.py which should be run each time an esp_err_t"]
+  #[doc = " error is modified, created or removed from the IDF project."]
+  #[doc = ""]
+  #[doc = " @param code esp_err_t error code"]
+  #[doc = " @return string error message"]
+  pub fn esp_err_to_name(code: esp_err_t) -> *const ::cty::c_char;
+}
 pub const esp_mac_type_t_ESP_MAC_WIFI_STA: esp_mac_type_t = 0;
 pub const esp_mac_type_t_ESP_MAC_WIFI_SOFTAP: esp_mac_type_t = 1;
 pub const esp_mac_type_t_ESP_MAC_BT: esp_mac_type_t = 2;
@@ -4,6 +4,33 @@ const fn bit(bit_nr: usize) -> usize {
   1 << bit_nr
 }
 
+/// esp_err_t value indicating success (no error)
+pub const ESP_OK: esp_err_t = 0;
+/// Generic esp_err_t code indicating failure
+pub const ESP_FAIL: esp_err_t = -1;
+/// Out of memory
+pub const ESP_ERR_NO_MEM: esp_err_t = 0x101;
+/// Invalid argument
+pub const ESP_ERR_INVALID_ARG: esp_err_t = 0x102;
+/// Invalid state
+pub const ESP_ERR_INVALID_STATE: esp_err_t = 0x103;
+/// Invalid size
+pub const ESP_ERR_INVALID_SIZE: esp_err_t = 0x104;
+/// Requested resource not found
+pub const ESP_ERR_NOT_FOUND: esp_err_t = 0x105;
+/// Operation or feature not supported
+pub const ESP_ERR_NOT_SUPPORTED: esp_err_t = 0x106;
+/// Operation timed out
+pub const ESP_ERR_TIMEOUT: esp_err_t = 0x107;
+/// Received response was invalid
+pub const ESP_ERR_INVALID_RESPONSE: esp_err_t = 0x108;
+/// CRC or checksum was invalid
+pub const ESP_ERR_INVALID_CRC: esp_err_t = 0x109;
+/// Version was invalid
+pub const ESP_ERR_INVALID_VERSION: esp_err_t = 0x10A;
+/// MAC address was invalid
+pub const ESP_ERR_INVALID_MAC: esp_err_t = 0x10B;
+
 pub const TWO_UNIVERSAL_MAC_ADDR: usize = 2usize;
 pub const FOUR_UNIVERSAL_MAC_ADDR: usize = 4usize;
 /// Chip has embedded flash memory
@@ -14,6 +41,18 @@ pub const CHIP_FEATURE_WIFI_BGN: usize = bit(1);
 pub const CHIP_FEATURE_BLE: usize = bit(4);
 /// Chip has Bluetooth Classic
 pub const CHIP_FEATURE_BT: usize = bit(5);
+/// Chip has IEEE 802.15.4
+pub const CHIP_FEATURE_IEEE802154: usize = bit(6);
+/// Chip has embedded psram
+pub const CHIP_FEATURE_EMB_PSRAM: usize = bit(7);
+
+// Chip models added to esp_chip_model_t by newer IDF versions
+/// ESP32-S3
+pub const CHIP_ESP32S3: esp_chip_model_t = 9;
+/// ESP32-C3
+pub const CHIP_ESP32C3: esp_chip_model_t = 5;
+/// ESP32-H2
+pub const CHIP_ESP32H2: esp_chip_model_t = 6;
 
 mod bindings;
 
@@ -0,0 +1,595 @@
+/* automatically generated by rust-bindgen 0.56.0 */
+
+#![allow(non_camel_case_types, non_upper_case_globals)]
+
+pub type BaseType_t = ::cty::c_int;
+pub type UBaseType_t = ::cty::c_uint;
+pub type TickType_t = u32;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct portMUX_TYPE {
+  pub owner: u32,
+  pub count: u32,
+}
+#[test]
+fn bindgen_test_layout_portMUX_TYPE() {
+  assert_eq!(
+    ::core::mem::size_of::<portMUX_TYPE>(),
+    8usize,
+    concat!("Size of: ", stringify!(portMUX_TYPE))
+  );
+  assert_eq!(
+    ::core::mem::align_of::<portMUX_TYPE>(),
+    4usize,
+    concat!("Alignment of ", stringify!(portMUX_TYPE))
+  );
+  assert_eq!(
+    unsafe { &(*(::core::ptr::null::<portMUX_TYPE>())).owner as *const _ as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(portMUX_TYPE),
+      "::",
+      stringify!(owner)
+    )
+  );
+  assert_eq!(
+    unsafe { &(*(::core::ptr::null::<portMUX_TYPE>())).count as *const _ as usize },
+    4usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(portMUX_TYPE),
+      "::",
+      stringify!(count)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tskTaskControlBlock {
+  _unused: [u8; 0],
+}
+pub type TaskHandle_t = *mut tskTaskControlBlock;
+pub type TaskFunction_t = ::core::option::Option<unsafe extern "C" fn(arg1: *mut ::cty::c_void)>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct QueueDefinition {
+  _unused: [u8; 0],
+}
+#[doc = " Type by which queues are referenced.  For example, a call to xQueueCreate()"]
+#[doc = " returns an QueueHandle_t variable that can then be used as a parameter to"]
+#[doc = " xQueueSend(), xQueueReceive(), etc."]
+pub type QueueHandle_t = *mut QueueDefinition;
+#[doc = " In line with software engineering best practice, FreeRTOS implements a strict"]
+#[doc = " data hiding policy, so the real queue structure is not accessible to the"]
+#[doc = " application.  StaticQueue_t is a dummy structure which has the same size and"]
+#[doc = " alignment requirements as the real queue structure, and is used to allocate"]
+#[doc = " statically allocated queues, semaphores and mutexes."]
+#[repr(C)]
+#[repr(align(8))]
+#[derive(Debug, Copy, Clone)]
+pub struct StaticQueue_t {
+  pub _bindgen_opaque_blob: [u64; 17usize],
+}
+#[test]
+fn bindgen_test_layout_StaticQueue_t() {
+  assert_eq!(
+    ::core::mem::size_of::<StaticQueue_t>(),
+    136usize,
+    concat!("Size of: ", stringify!(StaticQueue_t))
+  );
+  assert_eq!(
+    ::core::mem::align_of::<StaticQueue_t>(),
+    8usize,
+    concat!("Alignment of ", stringify!(StaticQueue_t))
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct EventGroupDef_t {
+  _unused: [u8; 0],
+}
+pub type EventGroupHandle_t = *mut EventGroupDef_t;
+pub type EventBits_t = TickType_t;
+#[doc = " StaticEventGroup_t is a dummy structure which has the same size and"]
+#[doc = " alignment requirements as the real event group structure, and is used to"]
+#[doc = " allocate statically allocated event groups."]
+#[repr(C)]
+#[repr(align(8))]
+#[derive(Debug, Copy, Clone)]
+pub struct StaticEventGroup_t {
+  pub _bindgen_opaque_blob: [u64; 8usize],
+}
+#[test]
+fn bindgen_test_layout_StaticEventGroup_t() {
+  assert_eq!(
+    ::core::mem::size_of::<StaticEventGroup_t>(),
+    64usize,
+    concat!("Size of: ", stringify!(StaticEventGroup_t))
+  );
+  assert_eq!(
+    ::core::mem::align_of::<StaticEventGroup_t>(),
+    8usize,
+    concat!("Alignment of ", stringify!(StaticEventGroup_t))
+  );
+}
+pub const eNotifyAction_eNoAction: eNotifyAction = 0;
+pub const eNotifyAction_eSetBits: eNotifyAction = 1;
+pub const eNotifyAction_eIncrement: eNotifyAction = 2;
+pub const eNotifyAction_eSetValueWithOverwrite: eNotifyAction = 3;
+pub const eNotifyAction_eSetValueWithoutOverwrite: eNotifyAction = 4;
+#[doc = " Actions that can be performed when vTaskNotify() is called."]
+pub type eNotifyAction = ::cty::c_uint;
+extern "C" {
+  #[doc = " Create a new task with a specified affinity."]
+  #[doc = ""]
+  #[doc = " This function is similar to xTaskCreate, but allows setting task affinity"]
+  #[doc = " in SMP system."]
+  #[doc = ""]
+  #[doc = " @param pvTaskCode Pointer to the task entry function.  Tasks"]
+  #[doc = " must be implemented to never return (i.e. continuous loop), or should be"]
+  #[doc = " terminated using vTaskDelete function."]
+  #[doc = ""]
+  #[doc = " @param pcName A descriptive name for the task.  This is mainly used to"]
+  #[doc = " facilitate debugging.  Max length defined by configMAX_TASK_NAME_LEN - default"]
+  #[doc = " is 16."]
+  #[doc = ""]
+  #[doc = " @param usStackDepth The size of the task stack specified as the number of"]
+  #[doc = " bytes. Note that this differs from vanilla FreeRTOS."]
+  #[doc = ""]
+  #[doc = " @param pvParameters Pointer that will be used as the parameter for the task"]
+  #[doc = " being created."]
+  #[doc = ""]
+  #[doc = " @param uxPriority The priority at which the task should run.  Systems that"]
+  #[doc = " include MPU support can optionally create tasks in a privileged (system)"]
+  #[doc = " mode by setting bit portPRIVILEGE_BIT of the priority parameter.  For"]
+  #[doc = " example, to create a privileged task at priority 2 the uxPriority parameter"]
+  #[doc = " should be set to ( 2 | portPRIVILEGE_BIT )."]
+  #[doc = ""]
+  #[doc = " @param pvCreatedTask Used to pass back a handle by which the created task"]
+  #[doc = " can be referenced."]
+  #[doc = ""]
+  #[doc = " @param xCoreID If the value is tskNO_AFFINITY, the created task is not"]
+  #[doc = " pinned to any CPU, and the scheduler can run it on any core available."]
+  #[doc = " Values 0 or 1 indicate the index number of the CPU which the task should"]
+  #[doc = " be pinned to. Specifying values larger than (portNUM_PROCESSORS - 1) will"]
+  #[doc = " cause the function to fail."]
+  #[doc = ""]
+  #[doc = " @return pdPASS if the task was successfully created and added to a ready"]
+  #[doc = " list, otherwise an error code defined in the file projdefs.h"]
+  pub fn xTaskCreatePinnedToCore(
+    pvTaskCode: TaskFunction_t,
+    pcName: *const ::cty::c_char,
+    usStackDepth: u32,
+    pvParameters: *mut ::cty::c_void,
+    uxPriority: UBaseType_t,
+    pvCreatedTask: *mut TaskHandle_t,
+    xCoreID: BaseType_t,
+  ) -> BaseType_t;
+}
+extern "C" {
+  #[doc = " Remove a task from the RTOS real time kernel's management."]
+  #[doc = ""]
+  #[doc = " The task being deleted will be removed from all ready, blocked, suspended"]
+  #[doc = " and event lists."]
+  #[doc = ""]
+  #[doc = " @param xTaskToDelete The handle of the task to be deleted.  Passing NULL will"]
+  #[doc = " cause the calling task to be deleted."]
+  pub fn vTaskDelete(xTaskToDelete: TaskHandle_t);
+}
+extern "C" {
+  #[doc = " Delay a task for a given number of ticks.  The actual time that the"]
+  #[doc = " task remains blocked depends on the tick rate.  The constant"]
+  #[doc = " portTICK_PERIOD_MS can be used to calculate real time from the tick"]
+  #[doc = " rate - with the resolution of one tick period."]
+  #[doc = ""]
+  #[doc = " INCLUDE_vTaskDelay must be defined as 1 for this function to be available."]
+  #[doc = " See the configuration section for more information."]
+  #[doc = ""]
+  #[doc = " vTaskDelay() specifies a time at which the task wishes to unblock relative to"]
+  #[doc = " the time at which vTaskDelay() is called."]
+  #[doc = ""]
+  #[doc = " @param xTicksToDelay The amount of time, in tick periods, that"]
+  #[doc = " the calling task should block."]
+  pub fn vTaskDelay(xTicksToDelay: TickType_t);
+}
+extern "C" {
+  #[doc = " Suspend a task."]
+  #[doc = ""]
+  #[doc = " When suspended, a task will never get any microcontroller processing time,"]
+  #[doc = " no matter what its priority."]
+  #[doc = ""]
+  #[doc = " Calls to vTaskSuspend are not accumulative -"]
+  #[doc = " i.e. calling vTaskSuspend () twice on the same task still only requires one"]
+  #[doc = " call to vTaskResume () to ready the suspended task."]
+  #[doc = ""]
+  #[doc = " @param xTaskToSuspend Handle to the task being suspended.  Passing a NULL"]
+  #[doc = " handle will cause the calling task to be suspended."]
+  pub fn vTaskSuspend(xTaskToSuspend: TaskHandle_t);
+}
+extern "C" {
+  #[doc = " Resumes a suspended task."]
+  #[doc = ""]
+  #[doc = " A task that has been suspended by one or more calls to vTaskSuspend ()"]
+  #[doc = " will be made available for running again by a single call to"]
+  #[doc = " vTaskResume ()."]
+  #[doc = ""]
+  #[doc = " @param xTaskToResume Handle to the task being readied."]
+  pub fn vTaskResume(xTaskToResume: TaskHandle_t);
+}
+extern "C" {
+  #[doc = " Returns the high water mark of the stack associated with xTask."]
+  #[doc = ""]
+  #[doc = " High water mark is the minimum free stack space there has been (in bytes"]
+  #[doc = " rather than words as found in vanilla FreeRTOS) since the task started."]
+  #[doc = " The smaller the returned number the closer the task has come to overflowing its stack."]
+  #[doc = ""]
+  #[doc = " @param xTask Handle of the task associated with the stack to be checked."]
+  #[doc = " Set xTask to NULL to check the stack of the calling task."]
+  #[doc = ""]
+  #[doc = " @return The smallest amount of free stack space there has been (in bytes"]
+  #[doc = " rather than words as found in vanilla FreeRTOS) since the task referenced by"]
+  #[doc = " xTask was created."]
+  pub fn uxTaskGetStackHighWaterMark(xTask: TaskHandle_t) -> UBaseType_t;
+}
+extern "C" {
+  #[doc = " @return The text (human readable) name of the task referenced by the handle"]
+  #[doc = " xTaskToQuery.  A task can query its own name by either passing in its own"]
+  #[doc = " handle, or by setting xTaskToQuery to NULL."]
+  pub fn pcTaskGetTaskName(xTaskToQuery: TaskHandle_t) -> *mut ::cty::c_char;
+}
+extern "C" {
+  #[doc = " Get the handle of idle task for the given CPU."]
+  #[doc = ""]
+  #[doc = " xTaskGetIdleTaskHandleForCPU() is only available if"]
+  #[doc = " INCLUDE_xTaskGetIdleTaskHandle is set to 1 in FreeRTOSConfig.h."]
+  #[doc = ""]
+  #[doc = " @param cpuid The CPU to get the handle for"]
+  #[doc = ""]
+  #[doc = " @return Idle task handle of a given cpu. It is not valid to call"]
+  #[doc = " xTaskGetIdleTaskHandleForCPU() before the scheduler has been started."]
+  pub fn xTaskGetIdleTaskHandleForCPU(cpuid: UBaseType_t) -> TaskHandle_t;
+}
+extern "C" {
+  #[doc = " Get the handle of the task running on the current CPU."]
+  #[doc = ""]
+  #[doc = " @return Handle of the current task"]
+  pub fn xTaskGetCurrentTaskHandle() -> TaskHandle_t;
+}
+extern "C" {
+  #[doc = " Send a notification to a task, optionally updating its notification value"]
+  #[doc = " as described by eAction."]
+  #[doc = ""]
+  #[doc = " @param xTaskToNotify The handle of the task being notified."]
+  #[doc = " @param ulValue Data that can be sent with the notification."]
+  #[doc = " @param eAction Specifies how the notification updates the task's notification value."]
+  #[doc = " @param pulPreviousNotificationValue Can be used to pass out the subject"]
+  #[doc = " task's notification value before any bits are modified by the notify function."]
+  #[doc = ""]
+  #[doc = " @return Dependent on the value of eAction. pdFAIL is only returned when"]
+  #[doc = " eAction is eSetValueWithoutOverwrite and the task already had a notification"]
+  #[doc = " pending, otherwise pdPASS is returned."]
+  pub fn xTaskGenericNotify(
+    xTaskToNotify: TaskHandle_t,
+    ulValue: u32,
+    eAction: eNotifyAction,
+    pulPreviousNotificationValue: *mut u32,
+  ) -> BaseType_t;
+}
+extern "C" {
+  #[doc = " A version of xTaskGenericNotify() that can be used from an interrupt service"]
+  #[doc = " routine (ISR)."]
+  #[doc = ""]
+  #[doc = " @param pxHigherPriorityTaskWoken Set to pdTRUE if sending the notification"]
+  #[doc = " caused the task to which the notification was sent to leave the Blocked"]
+  #[doc = " state, and the unblocked task has a priority higher than the currently"]
+  #[doc = " running task."]
+  pub fn xTaskGenericNotifyFromISR(
+    xTaskToNotify: TaskHandle_t,
+    ulValue: u32,
+    eAction: eNotifyAction,
+    pulPreviousNotificationValue: *mut u32,
+    pxHigherPriorityTaskWoken: *mut BaseType_t,
+  ) -> BaseType_t;
+}
+extern "C" {
+  #[doc = " Wait for a task notification, clearing bits in the notification value on"]
+  #[doc = " entry and on exit."]
+  #[doc = ""]
+  #[doc = " @param ulBitsToClearOnEntry Bits to clear in the notification value on entry."]
+  #[doc = " @param ulBitsToClearOnExit Bits to clear in the notification value before"]
+  #[doc = " the function exits, if a notification was received."]
+  #[doc = " @param pulNotificationValue Used to pass out the notification value."]
+  #[doc = " @param xTicksToWait The maximum amount of time to wait in the Blocked state."]
+  #[doc = ""]
+  #[doc = " @return pdTRUE if a notification was received, otherwise pdFALSE."]
+  pub fn xTaskNotifyWait(
+    ulBitsToClearOnEntry: u32,
+    ulBitsToClearOnExit: u32,
+    pulNotificationValue: *mut u32,
+    xTicksToWait: TickType_t,
+  ) -> BaseType_t;
+}
+extern "C" {
+  #[doc = " Use the notification value of the calling task as a counting or binary"]
+  #[doc = " semaphore."]
+  #[doc = ""]
+  #[doc = " @param xClearCountOnExit If pdFALSE the notification value is decremented"]
+  #[doc = " on exit, otherwise it is cleared to zero."]
+  #[doc = " @param xTicksToWait The maximum amount of time to wait in the Blocked state."]
+  #[doc = ""]
+  #[doc = " @return The task's notification count before it is either cleared to zero"]
+  #[doc = " or decremented."]
+  pub fn ulTaskNotifyTake(xClearCountOnExit: BaseType_t, xTicksToWait: TickType_t) -> u32;
+}
+extern "C" {
+  pub fn vTaskEnterCritical(mux: *mut portMUX_TYPE);
+}
+extern "C" {
+  pub fn vTaskExitCritical(mux: *mut portMUX_TYPE);
+}
+extern "C" {
+  pub fn vPortYield();
+}
+extern "C" {
+  pub fn vPortYieldFromInt();
+}
+extern "C" {
+  #[doc = " @brief Checks if the current core is in an ISR context"]
+  #[doc = ""]
+  #[doc = " ISR context includes NMI, ISRs, and the FreeRTOS tick hook."]
+  #[doc = ""]
+  #[doc = " @return true in ISR"]
+  #[doc = " @return false otherwise"]
+  pub fn xPortInIsrContext() -> BaseType_t;
+}
+extern "C" {
+  pub fn xQueueGenericCreate(
+    uxQueueLength: UBaseType_t,
+    uxItemSize: UBaseType_t,
+    ucQueueType: u8,
+  ) -> QueueHandle_t;
+}
+extern "C" {
+  pub fn xQueueGenericCreateStatic(
+    uxQueueLength: UBaseType_t,
+    uxItemSize: UBaseType_t,
+    pucQueueStorage: *mut u8,
+    pxStaticQueue: *mut StaticQueue_t,
+    ucQueueType: u8,
+  ) -> QueueHandle_t;
+}
+extern "C" {
+  pub fn xQueueCreateCountingSemaphore(
+    uxMaxCount: UBaseType_t,
+    uxInitialCount: UBaseType_t,
+  ) -> QueueHandle_t;
+}
+extern "C" {
+  pub fn xQueueCreateCountingSemaphoreStatic(
+    uxMaxCount: UBaseType_t,
+    uxInitialCount: UBaseType_t,
+    pxStaticQueue: *mut StaticQueue_t,
+  ) -> QueueHandle_t;
+}
+extern "C" {
+  pub fn xQueueCreateMutex(ucQueueType: u8) -> QueueHandle_t;
+}
+extern "C" {
+  pub fn xQueueCreateMutexStatic(
+    ucQueueType: u8,
+    pxStaticQueue: *mut StaticQueue_t,
+  ) -> QueueHandle_t;
+}
+extern "C" {
+  pub fn xQueueGenericSend(
+    xQueue: QueueHandle_t,
+    pvItemToQueue: *const ::cty::c_void,
+    xTicksToWait: TickType_t,
+    xCopyPosition: BaseType_t,
+  ) -> BaseType_t;
+}
+extern "C" {
+  pub fn xQueueGenericSendFromISR(
+    xQueue: QueueHandle_t,
+    pvItemToQueue: *const ::cty::c_void,
+    pxHigherPriorityTaskWoken: *mut BaseType_t,
+    xCopyPosition: BaseType_t,
+  ) -> BaseType_t;
+}
+extern "C" {
+  pub fn xQueueGiveFromISR(
+    xQueue: QueueHandle_t,
+    pxHigherPriorityTaskWoken: *mut BaseType_t,
+  ) -> BaseType_t;
+}
+extern "C" {
+  #[doc = " Receive an item from a queue.  The item is received by copy so a buffer of"]
+  #[doc = " adequate size must be provided.  The number of bytes copied into the buffer"]
+  #[doc = " was defined when the queue was created."]
+  #[doc = ""]
+  #[doc = " @param xQueue The handle to the queue from which the item is to be"]
+  #[doc = " received."]
+  #[doc = ""]
+  #[doc = " @param pvBuffer Pointer to the buffer into which the received item will"]
+  #[doc = " be copied."]
+  #[doc = ""]
+  #[doc = " @param xTicksToWait The maximum amount of time the task should block"]
+  #[doc = " waiting for an item to receive should the queue be empty at the time"]
+  #[doc = " of the call."]
+  #[doc = ""]
+  #[doc = " @return pdTRUE if an item was successfully received from the queue,"]
+  #[doc = " otherwise pdFALSE."]
+  pub fn xQueueReceive(
+    xQueue: QueueHandle_t,
+    pvBuffer: *mut ::cty::c_void,
+    xTicksToWait: TickType_t,
+  ) -> BaseType_t;
+}
+extern "C" {
+  #[doc = " Receive an item from a queue.  It is safe to use this function from within an"]
+  #[doc = " interrupt service routine."]
+  #[doc = ""]
+  #[doc = " @param xQueue The handle to the queue from which the item is to be"]
+  #[doc = " received."]
+  #[doc = ""]
+  #[doc = " @param pvBuffer Pointer to the buffer into which the received item will"]
+  #[doc = " be copied."]
+  #[doc = ""]
+  #[doc = " @param pxHigherPriorityTaskWoken A task may be blocked waiting for space to"]
+  #[doc = " become available on the queue.  If xQueueReceiveFromISR causes such a task"]
+  #[doc = " to unblock *pxTaskWoken will get set to pdTRUE, otherwise *pxTaskWoken will"]
+  #[doc = " remain unchanged."]
+  #[doc = ""]
+  #[doc = " @return pdTRUE if an item was successfully received from the queue,"]
+  #[doc = " otherwise pdFALSE."]
+  pub fn xQueueReceiveFromISR(
+    xQueue: QueueHandle_t,
+    pvBuffer: *mut ::cty::c_void,
+    pxHigherPriorityTaskWoken: *mut BaseType_t,
+  ) -> BaseType_t;
+}
+extern "C" {
+  pub fn xQueueSemaphoreTake(xQueue: QueueHandle_t, xTicksToWait: TickType_t) -> BaseType_t;
+}
+extern "C" {
+  pub fn xQueueTakeMutexRecursive(xMutex: QueueHandle_t, xTicksToWait: TickType_t) -> BaseType_t;
+}
+extern "C" {
+  pub fn xQueueGiveMutexRecursive(xMutex: QueueHandle_t) -> BaseType_t;
+}
+extern "C" {
+  #[doc = " Return the number of messages stored in a queue."]
+  #[doc = ""]
+  #[doc = " @param xQueue A handle to the queue being queried."]
+  #[doc = ""]
+  #[doc = " @return The number of messages available in the queue."]
+  pub fn uxQueueMessagesWaiting(xQueue: QueueHandle_t) -> UBaseType_t;
+}
+extern "C" {
+  #[doc = " A version of uxQueueMessagesWaiting() that can be called from an ISR."]
+  #[doc = " Return the number of messages stored in a queue."]
+  pub fn uxQueueMessagesWaitingFromISR(xQueue: QueueHandle_t) -> UBaseType_t;
+}
+extern "C" {
+  #[doc = " Delete a queue - freeing all the memory allocated for storing of items"]
+  #[doc = " placed on the queue."]
+  #[doc = ""]
+  #[doc = " @param xQueue A handle to the queue to be deleted."]
+  pub fn vQueueDelete(xQueue: QueueHandle_t);
+}
+extern "C" {
+  #[doc = " Create a new event group."]
+  #[doc = ""]
+  #[doc = " @return If the event group was created then a handle to the event group is"]
+  #[doc = " returned.  If there was insufficient FreeRTOS heap available to create the"]
+  #[doc = " event group then NULL is returned."]
+  pub fn xEventGroupCreate() -> EventGroupHandle_t;
+}
+extern "C" {
+  #[doc = " Create a new event group using memory provided by the caller."]
+  #[doc = ""]
+  #[doc = " @param pxEventGroupBuffer pxEventGroupBuffer must point to a variable of type"]
+  #[doc = " StaticEventGroup_t, which will be then be used to hold the event group's data"]
+  #[doc = " structures, removing the need for the memory to be allocated dynamically."]
+  #[doc = ""]
+  #[doc = " @return If the event group was created then a handle to the event group is"]
+  #[doc = " returned.  If pxEventGroupBuffer was NULL then NULL is returned."]
+  pub fn xEventGroupCreateStatic(pxEventGroupBuffer: *mut StaticEventGroup_t)
+    -> EventGroupHandle_t;
+}
+extern "C" {
+  #[doc = " [Potentially] block to wait for one or more bits to be set within a"]
+  #[doc = " previously created event group."]
+  #[doc = ""]
+  #[doc = " @param xEventGroup The event group in which the bits are being tested."]
+  #[doc = ""]
+  #[doc = " @param uxBitsToWaitFor A bitwise value that indicates the bit or bits to test"]
+  #[doc = " inside the event group."]
+  #[doc = ""]
+  #[doc = " @param xClearOnExit If xClearOnExit is set to pdTRUE then any bits within"]
+  #[doc = " uxBitsToWaitFor that are set within the event group will be cleared before"]
+  #[doc = " xEventGroupWaitBits() returns if the wait condition was met."]
+  #[doc = ""]
+  #[doc = " @param xWaitForAllBits If xWaitForAllBits is set to pdTRUE then"]
+  #[doc = " xEventGroupWaitBits() will return when either all the bits in uxBitsToWaitFor"]
+  #[doc = " are set or the specified block time expires.  If xWaitForAllBits is set to"]
+  #[doc = " pdFALSE then xEventGroupWaitBits() will return when any one of the bits set"]
+  #[doc = " in uxBitsToWaitFor is set or the specified block time expires."]
+  #[doc = ""]
+  #[doc = " @param xTicksToWait The maximum amount of time (specified in 'ticks') to wait"]
+  #[doc = " for one/all (depending on the xWaitForAllBits value) of the bits specified by"]
+  #[doc = " uxBitsToWaitFor to become set."]
+  #[doc = ""]
+  #[doc = " @return The value of the event group at the time either the bits being waited"]
+  #[doc = " for became set, or the block time expired."]
+  pub fn xEventGroupWaitBits(
+    xEventGroup: EventGroupHandle_t,
+    uxBitsToWaitFor: EventBits_t,
+    xClearOnExit: BaseType_t,
+    xWaitForAllBits: BaseType_t,
+    xTicksToWait: TickType_t,
+  ) -> EventBits_t;
+}
+extern "C" {
+  #[doc = " Clear bits within an event group.  This function cannot be called from an"]
+  #[doc = " interrupt."]
+  #[doc = ""]
+  #[doc = " @param xEventGroup The event group in which the bits are to be cleared."]
+  #[doc = ""]
+  #[doc = " @param uxBitsToClear A bitwise value that indicates the bit or bits to clear"]
+  #[doc = " in the event group."]
+  #[doc = ""]
+  #[doc = " @return The value of the event group before the specified bits were cleared."]
+  pub fn xEventGroupClearBits(
+    xEventGroup: EventGroupHandle_t,
+    uxBitsToClear: EventBits_t,
+  ) -> EventBits_t;
+}
+extern "C" {
+  #[doc = " Set bits within an event group."]
+  #[doc = " This function cannot be called from an interrupt.  xEventGroupSetBitsFromISR()"]
+  #[doc = " is a version that can be called from an interrupt."]
+  #[doc = ""]
+  #[doc = " @param xEventGroup The event group in which the bits are to be set."]
+  #[doc = ""]
+  #[doc = " @param uxBitsToSet A bitwise value that indicates the bit or bits to set."]
+  #[doc = ""]
+  #[doc = " @return The value of the event group at the time the call to"]
+  #[doc = " xEventGroupSetBits() returns."]
+  pub fn xEventGroupSetBits(
+    xEventGroup: EventGroupHandle_t,
+    uxBitsToSet: EventBits_t,
+  ) -> EventBits_t;
+}
+extern "C" {
+  #[doc = " A version of xEventGroupSetBits() that can be called from an interrupt."]
+  #[doc = ""]
+  #[doc = " Setting bits in an event group is not a deterministic operation, so the"]
+  #[doc = " operation is deferred to the RTOS daemon (timer service) task."]
+  #[doc = ""]
+  #[doc = " @return If the request to execute the function was posted successfully then"]
+  #[doc = " pdPASS is returned, otherwise pdFALSE is returned.  pdFALSE will be returned"]
+  #[doc = " if the timer service queue was full."]
+  pub fn xEventGroupSetBitsFromISR(
+    xEventGroup: EventGroupHandle_t,
+    uxBitsToSet: EventBits_t,
+    pxHigherPriorityTaskWoken: *mut BaseType_t,
+  ) -> BaseType_t;
+}
+extern "C" {
+  #[doc = " A version of xEventGroupGetBits() that can be called from an ISR."]
+  #[doc = ""]
+  #[doc = " @param xEventGroup The event group being queried."]
+  #[doc = ""]
+  #[doc = " @return The event group bits at the time xEventGroupGetBitsFromISR() was called."]
+  pub fn xEventGroupGetBitsFromISR(xEventGroup: EventGroupHandle_t) -> EventBits_t;
+}
+extern "C" {
+  #[doc = " Delete an event group that was previously created by a call to"]
+  #[doc = " xEventGroupCreate().  Tasks that are blocked on the event group will be"]
+  #[doc = " unblocked and obtain 0 as the event group's value."]
+  #[doc = ""]
+  #[doc = " @param xEventGroup The event group being deleted."]
+  pub fn vEventGroupDelete(xEventGroup: EventGroupHandle_t);
+}
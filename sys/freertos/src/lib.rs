@@ -0,0 +1,55 @@
+#![no_std]
+#![allow(non_upper_case_globals)]
+
+/// Tick rate of the FreeRTOS scheduler (`CONFIG_FREERTOS_HZ`)
+///
+/// Must match the application's sdkconfig.
+pub const configTICK_RATE_HZ: TickType_t = 100;
+
+/// Number of task priorities (`configMAX_PRIORITIES` in FreeRTOSConfig.h)
+pub const configMAX_PRIORITIES: UBaseType_t = 25;
+
+/// Smallest task stack size in bytes (`configMINIMAL_STACK_SIZE` in FreeRTOSConfig.h)
+pub const configMINIMAL_STACK_SIZE: u32 = 768;
+
+/// Number of cores the scheduler runs on (`portNUM_PROCESSORS`, 1 with `CONFIG_FREERTOS_UNICORE`)
+///
+/// Must match the application's sdkconfig.
+pub const portNUM_PROCESSORS: BaseType_t = 2;
+
+// projdefs.h
+pub const pdFALSE: BaseType_t = 0;
+pub const pdTRUE: BaseType_t = 1;
+pub const pdPASS: BaseType_t = pdTRUE;
+pub const pdFAIL: BaseType_t = pdFALSE;
+pub const errCOULD_NOT_ALLOCATE_REQUIRED_MEMORY: BaseType_t = -1;
+
+// portmacro.h
+pub const portMAX_DELAY: TickType_t = 0xffff_ffff;
+// portMUX_TYPE and the critical section functions assume CONFIG_FREERTOS_PORTMUX_DEBUG is disabled
+pub const portMUX_FREE_VAL: u32 = 0xb33f_ffff;
+pub const portMUX_INITIALIZER_UNLOCKED: portMUX_TYPE = portMUX_TYPE {
+  owner: portMUX_FREE_VAL,
+  count: 0,
+};
+
+// task.h
+pub const tskNO_AFFINITY: BaseType_t = 0x7fff_ffff;
+
+// queue.h
+pub const queueSEND_TO_BACK: BaseType_t = 0;
+pub const queueSEND_TO_FRONT: BaseType_t = 1;
+pub const queueOVERWRITE: BaseType_t = 2;
+pub const queueQUEUE_TYPE_BASE: u8 = 0;
+pub const queueQUEUE_TYPE_MUTEX: u8 = 1;
+pub const queueQUEUE_TYPE_COUNTING_SEMAPHORE: u8 = 2;
+pub const queueQUEUE_TYPE_BINARY_SEMAPHORE: u8 = 3;
+pub const queueQUEUE_TYPE_RECURSIVE_MUTEX: u8 = 4;
+
+// semphr.h
+pub const semGIVE_BLOCK_TIME: TickType_t = 0;
+pub const semSEMAPHORE_QUEUE_ITEM_LENGTH: UBaseType_t = 0;
+
+mod bindings;
+
+pub use bindings::*;
@@ -0,0 +1,443 @@
+/* automatically generated by rust-bindgen 0.56.0 */
+
+#![allow(non_camel_case_types, non_upper_case_globals)]
+
+pub type __intptr_t = ::cty::c_long;
+pub type intptr_t = __intptr_t;
+pub type esp_err_t = i32;
+
+extern "C" {
+  #[doc = " @brief Allocate a chunk of memory which has the given capabilities"]
+  #[doc = ""]
+  #[doc = " Equivalent semantics to libc malloc(), for capability-aware memory."]
+  #[doc = ""]
+  #[doc = " In IDF, ``malloc(p)`` is equivalent to ``heap_caps_malloc(p, MALLOC_CAP_8BIT)``."]
+  #[doc = ""]
+  #[doc = " @param size Size, in bytes, of the amount of memory to allocate"]
+  #[doc = " @param caps        Bitwise OR of MALLOC_CAP_* flags indicating the type"]
+  #[doc = "                    of memory to be returned"]
+  #[doc = ""]
+  #[doc = " @return A pointer to the memory allocated on success, NULL on failure"]
+  pub fn heap_caps_malloc(size: usize, caps: u32) -> *mut ::cty::c_void;
+}
+extern "C" {
+  #[doc = " @brief Free memory previously allocated via heap_caps_malloc() or heap_caps_realloc()."]
+  #[doc = ""]
+  #[doc = " Equivalent semantics to libc free(), for capability-aware memory."]
+  #[doc = ""]
+  #[doc = "  In IDF, ``free(p)`` is equivalent to ``heap_caps_free(p)``."]
+  #[doc = ""]
+  #[doc = " @param ptr Pointer to memory previously returned from heap_caps_malloc() or heap_caps_realloc(). Can be NULL."]
+  pub fn heap_caps_free(ptr: *mut ::cty::c_void);
+}
+extern "C" {
+  #[doc = " @brief Reallocate memory previously allocated via heap_caps_malloc() or heap_caps_realloc()."]
+  #[doc = ""]
+  #[doc = " Equivalent semantics to libc realloc(), for capability-aware memory."]
+  #[doc = ""]
+  #[doc = " In IDF, ``realloc(p, s)`` is equivalent to ``heap_caps_realloc(p, s, MALLOC_CAP_8BIT)``."]
+  #[doc = ""]
+  #[doc = " 'caps' parameter can be different to the capabilities that any original 'ptr' was allocated with. In this way,"]
+  #[doc = " realloc can be used to \"move\" a buffer if necessary to ensure it meets a new set of capabilities."]
+  #[doc = ""]
+  #[doc = " @param ptr Pointer to previously allocated memory, or NULL for a new allocation."]
+  #[doc = " @param size Size of the new buffer requested, or 0 to free the buffer."]
+  #[doc = " @param caps        Bitwise OR of MALLOC_CAP_* flags indicating the type"]
+  #[doc = "                    of memory desired for the new allocation."]
+  #[doc = ""]
+  #[doc = " @return Pointer to a new buffer of size 'size' with capabilities 'caps', or NULL if allocation failed."]
+  pub fn heap_caps_realloc(
+    ptr: *mut ::cty::c_void,
+    size: usize,
+    caps: ::cty::c_int,
+  ) -> *mut ::cty::c_void;
+}
+extern "C" {
+  #[doc = " @brief Allocate a aligned chunk of memory which has the given capabilities"]
+  #[doc = ""]
+  #[doc = " Equivalent semantics to libc aligned_alloc(), for capability-aware memory."]
+  #[doc = " @param alignment  How the pointer received needs to be aligned"]
+  #[doc = "                   must be a power of two"]
+  #[doc = " @param size Size, in bytes, of the amount of memory to allocate"]
+  #[doc = " @param caps        Bitwise OR of MALLOC_CAP_* flags indicating the type"]
+  #[doc = "                    of memory to be returned"]
+  #[doc = ""]
+  #[doc = " @return A pointer to the memory allocated on success, NULL on failure"]
+  pub fn heap_caps_aligned_alloc(
+    alignment: usize,
+    size: usize,
+    caps: ::cty::c_int,
+  ) -> *mut ::cty::c_void;
+}
+extern "C" {
+  #[doc = " @brief Used to deallocate memory previously allocated with heap_caps_aligned_alloc"]
+  #[doc = ""]
+  #[doc = " @param ptr Pointer to the memory allocated"]
+  #[doc = " @note This function is aimed to deallocate only memory allocated with"]
+  #[doc = "       heap_caps_aligned_alloc, memory allocated with heap_caps_malloc"]
+  #[doc = "       MUST not be passed to this function"]
+  pub fn heap_caps_aligned_free(ptr: *mut ::cty::c_void);
+}
+extern "C" {
+  #[doc = " @brief Allocate a chunk of memory which has the given capabilities. The initialized value in the memory is set to zero."]
+  #[doc = ""]
+  #[doc = " Equivalent semantics to libc calloc(), for capability-aware memory."]
+  #[doc = ""]
+  #[doc = " In IDF, ``calloc(p)`` is equivalent to ``heap_caps_calloc(p, MALLOC_CAP_8BIT)``."]
+  #[doc = ""]
+  #[doc = " @param n    Number of continuing chunks of memory to allocate"]
+  #[doc = " @param size Size, in bytes, of a chunk of memory to allocate"]
+  #[doc = " @param caps        Bitwise OR of MALLOC_CAP_* flags indicating the type"]
+  #[doc = "                    of memory to be returned"]
+  #[doc = ""]
+  #[doc = " @return A pointer to the memory allocated on success, NULL on failure"]
+  pub fn heap_caps_calloc(n: usize, size: usize, caps: u32) -> *mut ::cty::c_void;
+}
+extern "C" {
+  #[doc = " @brief Get the total free size of all the regions that have the given capabilities"]
+  #[doc = ""]
+  #[doc = " This function takes all regions capable of having the given capabilities allocated in them"]
+  #[doc = " and adds up the free space they have."]
+  #[doc = ""]
+  #[doc = " Note that because of heap fragmentation it is probably not possible to allocate a single block of memory"]
+  #[doc = " of this size. Use heap_caps_get_largest_free_block() for this purpose."]
+  #[doc = ""]
+  #[doc = " @param caps        Bitwise OR of MALLOC_CAP_* flags indicating the type"]
+  #[doc = "                    of memory"]
+  #[doc = ""]
+  #[doc = " @return Amount of free bytes in the regions"]
+  pub fn heap_caps_get_free_size(caps: u32) -> usize;
+}
+extern "C" {
+  #[doc = " @brief Get the largest free block of memory able to be allocated with the given capabilities."]
+  #[doc = ""]
+  #[doc = " Returns the largest value of ``s`` for which ``heap_caps_malloc(s, caps)`` will succeed."]
+  #[doc = ""]
+  #[doc = " @param caps        Bitwise OR of MALLOC_CAP_* flags indicating the type"]
+  #[doc = "                    of memory"]
+  #[doc = ""]
+  #[doc = " @return Size of largest free block in bytes."]
+  pub fn heap_caps_get_largest_free_block(caps: u32) -> usize;
+}
+#[doc = " @brief Structure to access heap metadata via multi_heap_get_info"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct multi_heap_info_t {
+  #[doc = "<  Total free bytes in the heap. Equivalent to multi_free_heap_size()."]
+  pub total_free_bytes: usize,
+  #[doc = "<  Total bytes allocated to data in the heap."]
+  pub total_allocated_bytes: usize,
+  #[doc = "<  Size of largest free block in the heap. This is the largest malloc-able size."]
+  pub largest_free_block: usize,
+  #[doc = "<  Lifetime minimum free heap size. Equivalent to multi_minimum_free_heap_size()."]
+  pub minimum_free_bytes: usize,
+  #[doc = "<  Number of (variable size) blocks allocated in the heap."]
+  pub allocated_blocks: usize,
+  #[doc = "<  Number of (variable size) free blocks in the heap."]
+  pub free_blocks: usize,
+  #[doc = "<  Total number of (variable size) blocks in the heap."]
+  pub total_blocks: usize,
+}
+#[test]
+fn bindgen_test_layout_multi_heap_info_t() {
+  assert_eq!(
+    ::core::mem::size_of::<multi_heap_info_t>(),
+    56usize,
+    concat!("Size of: ", stringify!(multi_heap_info_t))
+  );
+  assert_eq!(
+    ::core::mem::align_of::<multi_heap_info_t>(),
+    8usize,
+    concat!("Alignment of ", stringify!(multi_heap_info_t))
+  );
+  assert_eq!(
+    unsafe { &(*(::core::ptr::null::<multi_heap_info_t>())).total_free_bytes as *const _ as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(multi_heap_info_t),
+      "::",
+      stringify!(total_free_bytes)
+    )
+  );
+  assert_eq!(
+    unsafe {
+      &(*(::core::ptr::null::<multi_heap_info_t>())).total_allocated_bytes as *const _ as usize
+    },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(multi_heap_info_t),
+      "::",
+      stringify!(total_allocated_bytes)
+    )
+  );
+  assert_eq!(
+    unsafe {
+      &(*(::core::ptr::null::<multi_heap_info_t>())).largest_free_block as *const _ as usize
+    },
+    16usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(multi_heap_info_t),
+      "::",
+      stringify!(largest_free_block)
+    )
+  );
+  assert_eq!(
+    unsafe {
+      &(*(::core::ptr::null::<multi_heap_info_t>())).minimum_free_bytes as *const _ as usize
+    },
+    24usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(multi_heap_info_t),
+      "::",
+      stringify!(minimum_free_bytes)
+    )
+  );
+  assert_eq!(
+    unsafe { &(*(::core::ptr::null::<multi_heap_info_t>())).allocated_blocks as *const _ as usize },
+    32usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(multi_heap_info_t),
+      "::",
+      stringify!(allocated_blocks)
+    )
+  );
+  assert_eq!(
+    unsafe { &(*(::core::ptr::null::<multi_heap_info_t>())).free_blocks as *const _ as usize },
+    40usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(multi_heap_info_t),
+      "::",
+      stringify!(free_blocks)
+    )
+  );
+  assert_eq!(
+    unsafe { &(*(::core::ptr::null::<multi_heap_info_t>())).total_blocks as *const _ as usize },
+    48usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(multi_heap_info_t),
+      "::",
+      stringify!(total_blocks)
+    )
+  );
+}
+extern "C" {
+  #[doc = " @brief Get heap info for all regions with the given capabilities."]
+  #[doc = ""]
+  #[doc = " Calls multi_heap_info() on all heaps which share the given capabilities. The information returned is an aggregate"]
+  #[doc = " across all matching heaps. The meanings of fields are the same as defined for multi_heap_info_t, except that"]
+  #[doc = " ``minimum_free_bytes`` has the same caveats described in heap_caps_get_minimum_free_size()."]
+  #[doc = ""]
+  #[doc = " @param info        Pointer to a structure which will be filled with relevant"]
+  #[doc = "                    heap metadata."]
+  #[doc = " @param caps        Bitwise OR of MALLOC_CAP_* flags indicating the type"]
+  #[doc = "                    of memory"]
+  #[doc = ""]
+  pub fn heap_caps_get_info(info: *mut multi_heap_info_t, caps: u32);
+}
+extern "C" {
+  #[doc = " @brief Check integrity of all heaps"]
+  #[doc = ""]
+  #[doc = " Calls multi_heap_check on all heaps. Optionally print errors if the heaps are corrupted."]
+  #[doc = ""]
+  #[doc = " @param print_errors Print specific errors if heap corruption is found."]
+  #[doc = ""]
+  #[doc = " @return True if all heaps are valid, False if at least one heap is corrupt."]
+  pub fn heap_caps_check_integrity_all(print_errors: bool) -> bool;
+}
+extern "C" {
+  #[doc = " @brief Check integrity of heap memory around a given address."]
+  #[doc = ""]
+  #[doc = " This function can be used to check the integrity of a single region of heap memory,"]
+  #[doc = " which contains the given address."]
+  #[doc = ""]
+  #[doc = " This can be useful if debugging heap integrity for corruption at a known address,"]
+  #[doc = " as it has a lower overhead than checking all heap regions. Note that if the corrupt"]
+  #[doc = " address moves around between runs (due to timing or other factors) then this approach"]
+  #[doc = " won't work and you should call heap_caps_check_integrity or"]
+  #[doc = " heap_caps_check_integrity_all instead."]
+  #[doc = ""]
+  #[doc = " @note The entire heap region around the address is checked, not only the adjacent"]
+  #[doc = " heap blocks."]
+  #[doc = ""]
+  #[doc = " @param addr Address in memory. Check for corruption in region containing this address."]
+  #[doc = " @param print_errors Print specific errors if heap corruption is found."]
+  #[doc = ""]
+  #[doc = " @return True if the heap containing the specified address is valid,"]
+  #[doc = " False if at least one heap is corrupt or the address doesn't belong to a heap region."]
+  pub fn heap_caps_check_integrity_addr(addr: intptr_t, print_errors: bool) -> bool;
+}
+pub const heap_trace_mode_t_HEAP_TRACE_ALL: heap_trace_mode_t = 0;
+pub const heap_trace_mode_t_HEAP_TRACE_LEAKS: heap_trace_mode_t = 1;
+pub type heap_trace_mode_t = ::cty::c_uint;
+#[doc = " @brief Trace record data type. Stores information about an allocated region of memory."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct heap_trace_record_t {
+  #[doc = "< CCOUNT of the CPU when the allocation was made. LSB (bit value 1) is the CPU number (0 or 1)."]
+  pub ccount: u32,
+  #[doc = "< Address which was allocated"]
+  pub address: *mut ::cty::c_void,
+  #[doc = "< Size of the allocation"]
+  pub size: usize,
+  #[doc = "< Call stack of the caller which allocated the memory."]
+  pub alloced_by: [*mut ::cty::c_void; 2usize],
+  #[doc = "< Call stack of the caller which freed the memory (all zero if not freed.)"]
+  pub freed_by: [*mut ::cty::c_void; 2usize],
+}
+#[test]
+fn bindgen_test_layout_heap_trace_record_t() {
+  assert_eq!(
+    ::core::mem::size_of::<heap_trace_record_t>(),
+    56usize,
+    concat!("Size of: ", stringify!(heap_trace_record_t))
+  );
+  assert_eq!(
+    ::core::mem::align_of::<heap_trace_record_t>(),
+    8usize,
+    concat!("Alignment of ", stringify!(heap_trace_record_t))
+  );
+  assert_eq!(
+    unsafe { &(*(::core::ptr::null::<heap_trace_record_t>())).ccount as *const _ as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(heap_trace_record_t),
+      "::",
+      stringify!(ccount)
+    )
+  );
+  assert_eq!(
+    unsafe { &(*(::core::ptr::null::<heap_trace_record_t>())).address as *const _ as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(heap_trace_record_t),
+      "::",
+      stringify!(address)
+    )
+  );
+  assert_eq!(
+    unsafe { &(*(::core::ptr::null::<heap_trace_record_t>())).size as *const _ as usize },
+    16usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(heap_trace_record_t),
+      "::",
+      stringify!(size)
+    )
+  );
+  assert_eq!(
+    unsafe { &(*(::core::ptr::null::<heap_trace_record_t>())).alloced_by as *const _ as usize },
+    24usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(heap_trace_record_t),
+      "::",
+      stringify!(alloced_by)
+    )
+  );
+  assert_eq!(
+    unsafe { &(*(::core::ptr::null::<heap_trace_record_t>())).freed_by as *const _ as usize },
+    40usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(heap_trace_record_t),
+      "::",
+      stringify!(freed_by)
+    )
+  );
+}
+extern "C" {
+  #[doc = " @brief Initialise heap tracing in standalone mode."]
+  #[doc = ""]
+  #[doc = " This function must be called before any other heap tracing functions."]
+  #[doc = ""]
+  #[doc = " To disable heap tracing and allow the buffer to be freed, stop tracing and then call heap_trace_init_standalone(NULL, 0);"]
+  #[doc = ""]
+  #[doc = " @param record_buffer Provide a buffer to use for heap trace data. Must remain valid any time heap tracing is enabled, meaning"]
+  #[doc = " it must be allocated from internal memory not in PSRAM."]
+  #[doc = " @param num_records Size of the heap trace buffer, as number of record structures."]
+  #[doc = " @return"]
+  #[doc = "  - ESP_ERR_NOT_SUPPORTED Project was compiled without heap tracing enabled in menuconfig."]
+  #[doc = "  - ESP_ERR_INVALID_STATE Heap tracing is currently in progress."]
+  #[doc = "  - ESP_OK Heap tracing initialised successfully."]
+  pub fn heap_trace_init_standalone(
+    record_buffer: *mut heap_trace_record_t,
+    num_records: usize,
+  ) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief Start heap tracing. All heap allocations & frees will be traced, until heap_trace_stop() is called."]
+  #[doc = ""]
+  #[doc = " @note heap_trace_init_standalone() must be called to provide a valid buffer, before this function is called."]
+  #[doc = ""]
+  #[doc = " @note Calling this function while heap tracing is running will reset the heap trace state and continue tracing."]
+  #[doc = ""]
+  #[doc = " @param mode Mode for tracing."]
+  #[doc = " - HEAP_TRACE_ALL means all heap allocations and frees are traced."]
+  #[doc = " - HEAP_TRACE_LEAKS means only suspected memory leaks are traced. (When memory is freed, the record is removed from the trace buffer.)"]
+  #[doc = " @return"]
+  #[doc = " - ESP_ERR_NOT_SUPPORTED Project was compiled without heap tracing enabled in menuconfig."]
+  #[doc = " - ESP_ERR_INVALID_STATE A non-zero-length buffer has not been set via heap_trace_init_standalone()."]
+  #[doc = " - ESP_OK Tracing is started."]
+  pub fn heap_trace_start(mode: heap_trace_mode_t) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief Stop heap tracing."]
+  #[doc = ""]
+  #[doc = " @return"]
+  #[doc = " - ESP_ERR_NOT_SUPPORTED Project was compiled without heap tracing enabled in menuconfig."]
+  #[doc = " - ESP_ERR_INVALID_STATE Heap tracing was not in progress."]
+  #[doc = " - ESP_OK Heap tracing stopped.."]
+  pub fn heap_trace_stop() -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief Resume heap tracing which was previously stopped."]
+  #[doc = ""]
+  #[doc = " Unlike heap_trace_start(), this function does not clear the"]
+  #[doc = " buffer of any pre-existing trace records."]
+  #[doc = ""]
+  #[doc = " The heap trace mode is the same as when heap_trace_start() was"]
+  #[doc = " last called (or HEAP_TRACE_ALL if heap_trace_start() was never called)."]
+  #[doc = ""]
+  #[doc = " @return"]
+  #[doc = " - ESP_ERR_NOT_SUPPORTED Project was compiled without heap tracing enabled in menuconfig."]
+  #[doc = " - ESP_ERR_INVALID_STATE Heap tracing was already started."]
+  #[doc = " - ESP_OK Heap tracing resumed."]
+  pub fn heap_trace_resume() -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief Return number of records in the heap trace buffer"]
+  #[doc = ""]
+  #[doc = " It is safe to call this function while heap tracing is running."]
+  pub fn heap_trace_get_count() -> usize;
+}
+extern "C" {
+  #[doc = " @brief Return a raw record from the heap trace buffer"]
+  #[doc = ""]
+  #[doc = " @note It is safe to call this function while heap tracing is running, however in HEAP_TRACE_LEAK mode record indexing may"]
+  #[doc = " skip entries unless heap tracing is stopped first."]
+  #[doc = ""]
+  #[doc = " @param index Index (zero-based) of the record to return."]
+  #[doc = " @param[out] record Record where the heap trace record will be copied."]
+  #[doc = " @return"]
+  #[doc = " - ESP_ERR_NOT_SUPPORTED Project was compiled without heap tracing enabled in menuconfig."]
+  #[doc = " - ESP_ERR_INVALID_STATE Heap tracing was not initialised."]
+  #[doc = " - ESP_ERR_INVALID_ARG Index is out of bounds for current heap trace record count."]
+  #[doc = " - ESP_OK Record returned successfully."]
+  pub fn heap_trace_get(index: usize, record: *mut heap_trace_record_t) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief Dump heap trace record data to stdout"]
+  #[doc = ""]
+  #[doc = " @note It is safe to call this function while heap tracing is running, however in HEAP_TRACE_LEAK mode the dump may skip"]
+  #[doc = " entries unless heap tracing is stopped first."]
+  pub fn heap_trace_dump();
+}
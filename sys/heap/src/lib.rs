@@ -0,0 +1,47 @@
+#![no_std]
+
+const fn bit(bit_nr: u32) -> u32 {
+  1 << bit_nr
+}
+
+/// Memory must be able to run executable code
+pub const MALLOC_CAP_EXEC: u32 = bit(0);
+/// Memory must allow for aligned 32-bit data accesses
+pub const MALLOC_CAP_32BIT: u32 = bit(1);
+/// Memory must allow for 8/16/...-bit data accesses
+pub const MALLOC_CAP_8BIT: u32 = bit(2);
+/// Memory must be able to accessed by DMA
+pub const MALLOC_CAP_DMA: u32 = bit(3);
+/// Memory must be mapped to PID2 memory space (PIDs are not currently used)
+pub const MALLOC_CAP_PID2: u32 = bit(4);
+/// Memory must be mapped to PID3 memory space (PIDs are not currently used)
+pub const MALLOC_CAP_PID3: u32 = bit(5);
+/// Memory must be mapped to PID4 memory space (PIDs are not currently used)
+pub const MALLOC_CAP_PID4: u32 = bit(6);
+/// Memory must be mapped to PID5 memory space (PIDs are not currently used)
+pub const MALLOC_CAP_PID5: u32 = bit(7);
+/// Memory must be mapped to PID6 memory space (PIDs are not currently used)
+pub const MALLOC_CAP_PID6: u32 = bit(8);
+/// Memory must be mapped to PID7 memory space (PIDs are not currently used)
+pub const MALLOC_CAP_PID7: u32 = bit(9);
+/// Memory must be in SPI RAM
+pub const MALLOC_CAP_SPIRAM: u32 = bit(10);
+/// Memory must be internal; specifically it should not disappear when flash/spiram cache is switched off
+pub const MALLOC_CAP_INTERNAL: u32 = bit(11);
+/// Memory can be returned in a non-capability-specific memory allocation (e.g. malloc(), calloc()) call
+pub const MALLOC_CAP_DEFAULT: u32 = bit(12);
+/// Memory must be in IRAM and allow unaligned access
+pub const MALLOC_CAP_IRAM_8BIT: u32 = bit(13);
+/// Memory can't be used / list end marker
+pub const MALLOC_CAP_INVALID: u32 = bit(31);
+
+/// Number of stack frames saved per heap trace record (`CONFIG_HEAP_TRACING_STACK_DEPTH`)
+///
+/// The heap trace bindings are generated with this value (the IDF default). The size of
+/// [heap_trace_record_t] depends on it, so an application built with a different depth will
+/// write past the end of trace buffers.
+pub const HEAP_TRACING_STACK_DEPTH: usize = 2;
+
+mod bindings;
+
+pub use bindings::*;
@@ -0,0 +1,91 @@
+/* automatically generated by rust-bindgen 0.56.0 */
+
+#![allow(non_camel_case_types, non_upper_case_globals)]
+use super::va_list;
+
+pub const CONFIG_LOG_DEFAULT_LEVEL: u32 = 3;
+pub const CONFIG_LOG_COLORS: u32 = 1;
+#[doc = "!< No log output"]
+pub const esp_log_level_t_ESP_LOG_NONE: esp_log_level_t = 0;
+#[doc = "!< Critical errors, software module can not recover on its own"]
+pub const esp_log_level_t_ESP_LOG_ERROR: esp_log_level_t = 1;
+#[doc = "!< Error conditions from which recovery measures have been taken"]
+pub const esp_log_level_t_ESP_LOG_WARN: esp_log_level_t = 2;
+#[doc = "!< Information messages which describe normal flow of events"]
+pub const esp_log_level_t_ESP_LOG_INFO: esp_log_level_t = 3;
+#[doc = "!< Extra information which is not necessary for normal use (values, pointers, sizes, etc)."]
+pub const esp_log_level_t_ESP_LOG_DEBUG: esp_log_level_t = 4;
+#[doc = "!< Bigger chunks of debugging information, or frequent messages which can potentially flood the output."]
+pub const esp_log_level_t_ESP_LOG_VERBOSE: esp_log_level_t = 5;
+#[doc = " @brief Log level"]
+#[doc = ""]
+pub type esp_log_level_t = ::cty::c_uint;
+pub type vprintf_like_t = ::core::option::Option<
+  unsafe extern "C" fn(arg1: *const ::cty::c_char, arg2: va_list) -> ::cty::c_int,
+>;
+extern "C" {
+  #[doc = " @brief Set log level for given tag"]
+  #[doc = ""]
+  #[doc = " If logging for given component has already been enabled, changes previous setting."]
+  #[doc = ""]
+  #[doc = " Note that this function can not raise log level above the level set using"]
+  #[doc = " CONFIG_LOG_DEFAULT_LEVEL setting in menuconfig."]
+  #[doc = ""]
+  #[doc = " To raise log level above the default one for a given file, define"]
+  #[doc = " LOG_LOCAL_LEVEL to one of the ESP_LOG_* values, before including"]
+  #[doc = " esp_log.h in this file."]
+  #[doc = ""]
+  #[doc = " @param tag Tag of the log entries to enable. Must be a non-NULL zero terminated string."]
+  #[doc = "            Value \"*\" resets log level for all tags to the given value."]
+  #[doc = ""]
+  #[doc = " @param level  Selects log level to enable. Only logs at this and lower verbosity"]
+  #[doc = " levels will be shown."]
+  pub fn esp_log_level_set(tag: *const ::cty::c_char, level: esp_log_level_t);
+}
+extern "C" {
+  #[doc = " @brief Set function used to output log entries"]
+  #[doc = ""]
+  #[doc = " By default, log output goes to UART0. This function can be used to redirect log"]
+  #[doc = " output to some other destination, such as file or network. Returns the original"]
+  #[doc = " log handler, which may be necessary to return output to the previous destination."]
+  #[doc = ""]
+  #[doc = " @param func new Function used for output. Must have same signature as vprintf."]
+  #[doc = ""]
+  #[doc = " @return func old Function used for output."]
+  pub fn esp_log_set_vprintf(func: vprintf_like_t) -> vprintf_like_t;
+}
+extern "C" {
+  #[doc = " @brief Function which returns timestamp to be used in log output"]
+  #[doc = ""]
+  #[doc = " This function is used in expansion of ESP_LOGx macros."]
+  #[doc = " In the 2nd stage bootloader, and at early application startup stage"]
+  #[doc = " this function uses CPU cycle counter as time source. Later when"]
+  #[doc = " FreeRTOS scheduler start running, it switches to FreeRTOS tick count."]
+  #[doc = ""]
+  #[doc = " For now, we ignore millisecond counter overflow."]
+  #[doc = ""]
+  #[doc = " @return timestamp, in milliseconds"]
+  pub fn esp_log_timestamp() -> u32;
+}
+extern "C" {
+  #[doc = " @brief Write message into the log"]
+  #[doc = ""]
+  #[doc = " This function is not intended to be used directly. Instead, use one of"]
+  #[doc = " ESP_LOGE, ESP_LOGW, ESP_LOGI, ESP_LOGD, ESP_LOGV macros."]
+  #[doc = ""]
+  #[doc = " This function or these macros should not be used from an interrupt."]
+  pub fn esp_log_write(
+    level: esp_log_level_t,
+    tag: *const ::cty::c_char,
+    format: *const ::cty::c_char,
+    ...
+  );
+}
+extern "C" {
+  pub fn vsnprintf(
+    __s: *mut ::cty::c_char,
+    __maxlen: usize,
+    __format: *const ::cty::c_char,
+    __arg: va_list,
+  ) -> ::cty::c_int;
+}
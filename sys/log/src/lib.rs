@@ -0,0 +1,33 @@
+#![no_std]
+#![allow(non_camel_case_types, non_upper_case_globals)]
+
+// esp_log.h
+pub const LOG_COLOR_E: &str = "\x1b[0;31m";
+pub const LOG_COLOR_W: &str = "\x1b[0;33m";
+pub const LOG_COLOR_I: &str = "\x1b[0;32m";
+pub const LOG_COLOR_D: &str = "";
+pub const LOG_COLOR_V: &str = "";
+pub const LOG_RESET_COLOR: &str = "\x1b[0m";
+
+/// Variable argument list, as defined by the xtensa ABI
+///
+/// `va_list` is a struct on xtensa, which is passed by value.
+#[cfg(target_arch = "xtensa")]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct va_list {
+  pub __va_stk: *mut cty::c_int,
+  pub __va_reg: *mut cty::c_int,
+  pub __va_ndx: cty::c_int,
+}
+
+#[cfg(target_arch = "xtensa")]
+const _: [(); 12] = [(); core::mem::size_of::<va_list>()];
+
+/// Placeholder for other targets, so the crate can be type checked on the host
+#[cfg(not(target_arch = "xtensa"))]
+pub type va_list = *mut cty::c_void;
+
+mod bindings;
+
+pub use bindings::*;
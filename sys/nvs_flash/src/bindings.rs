@@ -0,0 +1,479 @@
+/* automatically generated by rust-bindgen 0.56.0 */
+
+#![allow(non_camel_case_types, non_upper_case_globals)]
+
+pub type esp_err_t = i32;
+#[doc = " Opaque pointer type representing non-volatile storage handle"]
+pub type nvs_handle_t = u32;
+#[doc = "!< Read only"]
+pub const nvs_open_mode_t_NVS_READONLY: nvs_open_mode_t = 0;
+#[doc = "!< Read and write"]
+pub const nvs_open_mode_t_NVS_READWRITE: nvs_open_mode_t = 1;
+#[doc = " @brief Mode of opening the non-volatile storage"]
+pub type nvs_open_mode_t = ::cty::c_uint;
+#[doc = "!< Type uint8_t"]
+pub const nvs_type_t_NVS_TYPE_U8: nvs_type_t = 1;
+#[doc = "!< Type int8_t"]
+pub const nvs_type_t_NVS_TYPE_I8: nvs_type_t = 17;
+#[doc = "!< Type uint16_t"]
+pub const nvs_type_t_NVS_TYPE_U16: nvs_type_t = 2;
+#[doc = "!< Type int16_t"]
+pub const nvs_type_t_NVS_TYPE_I16: nvs_type_t = 18;
+#[doc = "!< Type uint32_t"]
+pub const nvs_type_t_NVS_TYPE_U32: nvs_type_t = 4;
+#[doc = "!< Type int32_t"]
+pub const nvs_type_t_NVS_TYPE_I32: nvs_type_t = 20;
+#[doc = "!< Type uint64_t"]
+pub const nvs_type_t_NVS_TYPE_U64: nvs_type_t = 8;
+#[doc = "!< Type int64_t"]
+pub const nvs_type_t_NVS_TYPE_I64: nvs_type_t = 24;
+#[doc = "!< Type string"]
+pub const nvs_type_t_NVS_TYPE_STR: nvs_type_t = 33;
+#[doc = "!< Type blob"]
+pub const nvs_type_t_NVS_TYPE_BLOB: nvs_type_t = 66;
+#[doc = "!< Must be last"]
+pub const nvs_type_t_NVS_TYPE_ANY: nvs_type_t = 255;
+#[doc = " @brief Types of variables"]
+#[doc = ""]
+pub type nvs_type_t = ::cty::c_uint;
+#[doc = " @brief information about entry obtained from nvs_entry_info function"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct nvs_entry_info_t {
+  #[doc = "!< Namespace to which key-value belong"]
+  pub namespace_name: [::cty::c_char; 16usize],
+  #[doc = "!< Key of stored key-value pair"]
+  pub key: [::cty::c_char; 16usize],
+  #[doc = "!< Type of stored key-value pair"]
+  pub type_: nvs_type_t,
+}
+#[test]
+fn bindgen_test_layout_nvs_entry_info_t() {
+  assert_eq!(
+    ::core::mem::size_of::<nvs_entry_info_t>(),
+    36usize,
+    concat!("Size of: ", stringify!(nvs_entry_info_t))
+  );
+  assert_eq!(
+    ::core::mem::align_of::<nvs_entry_info_t>(),
+    4usize,
+    concat!("Alignment of ", stringify!(nvs_entry_info_t))
+  );
+  assert_eq!(
+    unsafe { &(*(::core::ptr::null::<nvs_entry_info_t>())).namespace_name as *const _ as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(nvs_entry_info_t),
+      "::",
+      stringify!(namespace_name)
+    )
+  );
+  assert_eq!(
+    unsafe { &(*(::core::ptr::null::<nvs_entry_info_t>())).key as *const _ as usize },
+    16usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(nvs_entry_info_t),
+      "::",
+      stringify!(key)
+    )
+  );
+  assert_eq!(
+    unsafe { &(*(::core::ptr::null::<nvs_entry_info_t>())).type_ as *const _ as usize },
+    32usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(nvs_entry_info_t),
+      "::",
+      stringify!(type_)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct nvs_opaque_iterator_t {
+  _unused: [u8; 0],
+}
+#[doc = " Opaque pointer type representing iterator to nvs entries"]
+pub type nvs_iterator_t = *mut nvs_opaque_iterator_t;
+extern "C" {
+  #[doc = " @brief Open non-volatile storage with a given namespace from specified partition"]
+  #[doc = ""]
+  #[doc = " The behaviour is same as nvs_open() API. However this API can operate on a specified NVS"]
+  #[doc = " partition instead of default NVS partition. Note that the specified partition must be registered"]
+  #[doc = " with NVS using nvs_flash_init_partition() API."]
+  #[doc = ""]
+  #[doc = " @param[in]  part_name    Label (name) of the partition of interest for object read/write/erase"]
+  #[doc = " @param[in]  name         Namespace name. Maximal length is (NVS_KEY_NAME_MAX_SIZE-1) characters. Shouldn't be empty."]
+  #[doc = " @param[in]  open_mode    NVS_READWRITE or NVS_READONLY. If NVS_READONLY, will"]
+  #[doc = "                          open a handle for reading only. All write requests will"]
+  #[doc = "                          be rejected for this handle."]
+  #[doc = " @param[out] out_handle   If successful (return code is zero), handle will be"]
+  #[doc = "                          returned in this argument."]
+  #[doc = ""]
+  #[doc = " @return"]
+  #[doc = "             - ESP_OK if storage handle was opened successfully"]
+  #[doc = "             - ESP_ERR_NVS_NOT_INITIALIZED if the storage driver is not initialized"]
+  #[doc = "             - ESP_ERR_NVS_PART_NOT_FOUND if the partition with label \"nvs\" is not found"]
+  #[doc = "             - ESP_ERR_NVS_NOT_FOUND id namespace doesn't exist yet and"]
+  #[doc = "               mode is NVS_READONLY"]
+  #[doc = "             - ESP_ERR_NVS_INVALID_NAME if namespace name doesn't satisfy constraints"]
+  #[doc = "             - other error codes from the underlying storage driver"]
+  pub fn nvs_open_from_partition(
+    part_name: *const ::cty::c_char,
+    name: *const ::cty::c_char,
+    open_mode: nvs_open_mode_t,
+    out_handle: *mut nvs_handle_t,
+  ) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief      set int8_t value for given key"]
+  #[doc = ""]
+  #[doc = " Set value for the key, given its name. Note that the actual storage will not be updated"]
+  #[doc = " until nvs_commit is called."]
+  pub fn nvs_set_i8(handle: nvs_handle_t, key: *const ::cty::c_char, value: i8) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief      set uint8_t value for given key"]
+  #[doc = ""]
+  #[doc = " Set value for the key, given its name. Note that the actual storage will not be updated"]
+  #[doc = " until nvs_commit is called."]
+  pub fn nvs_set_u8(handle: nvs_handle_t, key: *const ::cty::c_char, value: u8) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief      set int16_t value for given key"]
+  #[doc = ""]
+  #[doc = " Set value for the key, given its name. Note that the actual storage will not be updated"]
+  #[doc = " until nvs_commit is called."]
+  pub fn nvs_set_i16(handle: nvs_handle_t, key: *const ::cty::c_char, value: i16) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief      set uint16_t value for given key"]
+  #[doc = ""]
+  #[doc = " Set value for the key, given its name. Note that the actual storage will not be updated"]
+  #[doc = " until nvs_commit is called."]
+  pub fn nvs_set_u16(handle: nvs_handle_t, key: *const ::cty::c_char, value: u16) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief      set int32_t value for given key"]
+  #[doc = ""]
+  #[doc = " Set value for the key, given its name. Note that the actual storage will not be updated"]
+  #[doc = " until nvs_commit is called."]
+  pub fn nvs_set_i32(handle: nvs_handle_t, key: *const ::cty::c_char, value: i32) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief      set uint32_t value for given key"]
+  #[doc = ""]
+  #[doc = " Set value for the key, given its name. Note that the actual storage will not be updated"]
+  #[doc = " until nvs_commit is called."]
+  pub fn nvs_set_u32(handle: nvs_handle_t, key: *const ::cty::c_char, value: u32) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief      set int64_t value for given key"]
+  #[doc = ""]
+  #[doc = " Set value for the key, given its name. Note that the actual storage will not be updated"]
+  #[doc = " until nvs_commit is called."]
+  pub fn nvs_set_i64(handle: nvs_handle_t, key: *const ::cty::c_char, value: i64) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief      set uint64_t value for given key"]
+  #[doc = ""]
+  #[doc = " Set value for the key, given its name. Note that the actual storage will not be updated"]
+  #[doc = " until nvs_commit is called."]
+  pub fn nvs_set_u64(handle: nvs_handle_t, key: *const ::cty::c_char, value: u64) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief       set string for given key"]
+  #[doc = ""]
+  #[doc = " Set value for the key, given its name. Note that the actual storage will not be updated"]
+  #[doc = " until nvs_commit is called."]
+  #[doc = ""]
+  #[doc = " @param[in]  value  The value to set."]
+  pub fn nvs_set_str(
+    handle: nvs_handle_t,
+    key: *const ::cty::c_char,
+    value: *const ::cty::c_char,
+  ) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief       set variable length binary value for given key"]
+  #[doc = ""]
+  #[doc = " This family of functions set value for the key, given its name. Note that"]
+  #[doc = " actual storage will not be updated until nvs_commit function is called."]
+  #[doc = ""]
+  #[doc = " @param[in]  length   length of binary value to set, in bytes; Maximum length is"]
+  #[doc = "                       508000 bytes or (97.6% of the partition size - 4000) bytes"]
+  #[doc = "                       whichever is lower."]
+  pub fn nvs_set_blob(
+    handle: nvs_handle_t,
+    key: *const ::cty::c_char,
+    value: *const ::cty::c_void,
+    length: usize,
+  ) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief      get int8_t value for given key"]
+  #[doc = ""]
+  #[doc = " If key doesn't exist or the requested variable type doesn't match the type which was"]
+  #[doc = " used when setting a value, an error is returned."]
+  #[doc = ""]
+  #[doc = " In case of any error, out_value is not modified."]
+  pub fn nvs_get_i8(
+    handle: nvs_handle_t,
+    key: *const ::cty::c_char,
+    out_value: *mut i8,
+  ) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief      get uint8_t value for given key"]
+  #[doc = ""]
+  #[doc = " If key doesn't exist or the requested variable type doesn't match the type which was"]
+  #[doc = " used when setting a value, an error is returned."]
+  #[doc = ""]
+  #[doc = " In case of any error, out_value is not modified."]
+  pub fn nvs_get_u8(
+    handle: nvs_handle_t,
+    key: *const ::cty::c_char,
+    out_value: *mut u8,
+  ) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief      get int16_t value for given key"]
+  #[doc = ""]
+  #[doc = " If key doesn't exist or the requested variable type doesn't match the type which was"]
+  #[doc = " used when setting a value, an error is returned."]
+  #[doc = ""]
+  #[doc = " In case of any error, out_value is not modified."]
+  pub fn nvs_get_i16(
+    handle: nvs_handle_t,
+    key: *const ::cty::c_char,
+    out_value: *mut i16,
+  ) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief      get uint16_t value for given key"]
+  #[doc = ""]
+  #[doc = " If key doesn't exist or the requested variable type doesn't match the type which was"]
+  #[doc = " used when setting a value, an error is returned."]
+  #[doc = ""]
+  #[doc = " In case of any error, out_value is not modified."]
+  pub fn nvs_get_u16(
+    handle: nvs_handle_t,
+    key: *const ::cty::c_char,
+    out_value: *mut u16,
+  ) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief      get int32_t value for given key"]
+  #[doc = ""]
+  #[doc = " If key doesn't exist or the requested variable type doesn't match the type which was"]
+  #[doc = " used when setting a value, an error is returned."]
+  #[doc = ""]
+  #[doc = " In case of any error, out_value is not modified."]
+  pub fn nvs_get_i32(
+    handle: nvs_handle_t,
+    key: *const ::cty::c_char,
+    out_value: *mut i32,
+  ) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief      get uint32_t value for given key"]
+  #[doc = ""]
+  #[doc = " If key doesn't exist or the requested variable type doesn't match the type which was"]
+  #[doc = " used when setting a value, an error is returned."]
+  #[doc = ""]
+  #[doc = " In case of any error, out_value is not modified."]
+  pub fn nvs_get_u32(
+    handle: nvs_handle_t,
+    key: *const ::cty::c_char,
+    out_value: *mut u32,
+  ) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief      get int64_t value for given key"]
+  #[doc = ""]
+  #[doc = " If key doesn't exist or the requested variable type doesn't match the type which was"]
+  #[doc = " used when setting a value, an error is returned."]
+  #[doc = ""]
+  #[doc = " In case of any error, out_value is not modified."]
+  pub fn nvs_get_i64(
+    handle: nvs_handle_t,
+    key: *const ::cty::c_char,
+    out_value: *mut i64,
+  ) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief      get uint64_t value for given key"]
+  #[doc = ""]
+  #[doc = " If key doesn't exist or the requested variable type doesn't match the type which was"]
+  #[doc = " used when setting a value, an error is returned."]
+  #[doc = ""]
+  #[doc = " In case of any error, out_value is not modified."]
+  pub fn nvs_get_u64(
+    handle: nvs_handle_t,
+    key: *const ::cty::c_char,
+    out_value: *mut u64,
+  ) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief      get string value for given key"]
+  #[doc = ""]
+  #[doc = " These functions retrieve the data of an entry, given its key. If key does not exist, or the"]
+  #[doc = " requested variable type doesn't match the type which was used when setting a value, an error"]
+  #[doc = " is returned."]
+  #[doc = ""]
+  #[doc = " In case of any error, out_value is not modified."]
+  #[doc = ""]
+  #[doc = " All functions expect out_value to be a pointer to an already allocated variable"]
+  #[doc = " of the given type."]
+  #[doc = ""]
+  #[doc = " nvs_get_str and nvs_get_blob functions support WinAPI-style length queries."]
+  #[doc = " To get the size necessary to store the value, call nvs_get_str or nvs_get_blob"]
+  #[doc = " with zero out_value and non-zero pointer to length. Variable pointed to"]
+  #[doc = " by length argument will be set to the required length. For nvs_get_str,"]
+  #[doc = " this length includes the zero terminator. When calling nvs_get_str and"]
+  #[doc = " nvs_get_blob with non-zero out_value, length has to be non-zero and has to"]
+  #[doc = " point to the length available in out_value."]
+  pub fn nvs_get_str(
+    handle: nvs_handle_t,
+    key: *const ::cty::c_char,
+    out_value: *mut ::cty::c_char,
+    length: *mut usize,
+  ) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief      get blob value for given key"]
+  #[doc = ""]
+  #[doc = " This function behaves the same as nvs_get_str, except for the data type."]
+  pub fn nvs_get_blob(
+    handle: nvs_handle_t,
+    key: *const ::cty::c_char,
+    out_value: *mut ::cty::c_void,
+    length: *mut usize,
+  ) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief      Erase key-value pair with given key name."]
+  #[doc = ""]
+  #[doc = " Note that actual storage may not be updated until nvs_commit function is called."]
+  pub fn nvs_erase_key(handle: nvs_handle_t, key: *const ::cty::c_char) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief      Erase all key-value pairs in a namespace"]
+  #[doc = ""]
+  #[doc = " Note that actual storage may not be updated until nvs_commit function is called."]
+  pub fn nvs_erase_all(handle: nvs_handle_t) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief      Write any pending changes to non-volatile storage"]
+  #[doc = ""]
+  #[doc = " After setting any values, nvs_commit() must be called to ensure changes are written"]
+  #[doc = " to non-volatile storage. Individual implementations may write to storage at other times,"]
+  #[doc = " but this is not guaranteed."]
+  pub fn nvs_commit(handle: nvs_handle_t) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief      Close the storage handle and free any allocated resources"]
+  #[doc = ""]
+  #[doc = " This function should be called for each handle opened with nvs_open once"]
+  #[doc = " the handle is not in use any more. Closing the handle may not automatically"]
+  #[doc = " write the changes to nonvolatile storage. This has to be done explicitly using"]
+  #[doc = " nvs_commit function."]
+  #[doc = " Once this function is called on a handle, the handle should no longer be used."]
+  pub fn nvs_close(handle: nvs_handle_t);
+}
+extern "C" {
+  #[doc = " @brief       Create an iterator to enumerate NVS entries based on one or more parameters"]
+  #[doc = ""]
+  #[doc = " @param[in]  part_name       Partition name"]
+  #[doc = ""]
+  #[doc = " @param[in]  namespace_name  Set this value if looking for entries with"]
+  #[doc = "                             a specific namespace. Pass NULL otherwise."]
+  #[doc = ""]
+  #[doc = " @param[in]  type            One of nvs_type_t values."]
+  #[doc = ""]
+  #[doc = " @return"]
+  #[doc = "          Iterator used to enumerate all the entries found,"]
+  #[doc = "          or NULL if no entry satisfying criteria was found."]
+  #[doc = "          Iterator obtained through this function has to be released"]
+  #[doc = "          using nvs_release_iterator when not used any more."]
+  pub fn nvs_entry_find(
+    part_name: *const ::cty::c_char,
+    namespace_name: *const ::cty::c_char,
+    type_: nvs_type_t,
+  ) -> nvs_iterator_t;
+}
+extern "C" {
+  #[doc = " @brief       Returns next item matching the iterator criteria, NULL if no such item exists."]
+  #[doc = ""]
+  #[doc = " Note that any copies of the iterator will be invalid after this call."]
+  #[doc = ""]
+  #[doc = " @param[in]  iterator     Iterator obtained from nvs_entry_find function. Must be non-NULL."]
+  #[doc = ""]
+  #[doc = " @return"]
+  #[doc = "          NULL if no entry was found, valid nvs_iterator_t otherwise."]
+  pub fn nvs_entry_next(iterator: nvs_iterator_t) -> nvs_iterator_t;
+}
+extern "C" {
+  #[doc = " @brief       Fills nvs_entry_info_t structure with information about entry pointed to by the iterator."]
+  #[doc = ""]
+  #[doc = " @param[in]  iterator     Iterator obtained from nvs_entry_find or nvs_entry_next function. Must be non-NULL."]
+  #[doc = ""]
+  #[doc = " @param[out] out_info     Structure to which entry information is copied."]
+  pub fn nvs_entry_info(iterator: nvs_iterator_t, out_info: *mut nvs_entry_info_t);
+}
+extern "C" {
+  #[doc = " @brief       Release iterator"]
+  #[doc = ""]
+  #[doc = " @param[in]  iterator    Release iterator obtained from nvs_entry_find function. NULL argument is allowed."]
+  pub fn nvs_release_iterator(iterator: nvs_iterator_t);
+}
+extern "C" {
+  #[doc = " @brief Initialize the default NVS partition."]
+  #[doc = ""]
+  #[doc = " This API initialises the default NVS partition. The default NVS partition"]
+  #[doc = " is the one that is labeled \"nvs\" in the partition table."]
+  #[doc = ""]
+  #[doc = " @return"]
+  #[doc = "      - ESP_OK if storage was successfully initialized."]
+  #[doc = "      - ESP_ERR_NVS_NO_FREE_PAGES if the NVS storage contains no empty pages"]
+  #[doc = "        (which may happen if NVS partition was truncated)"]
+  #[doc = "      - ESP_ERR_NOT_FOUND if no partition with label \"nvs\" is found in the partition table"]
+  #[doc = "      - one of the error codes from the underlying flash storage driver"]
+  pub fn nvs_flash_init() -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief Initialize NVS flash storage for the specified partition."]
+  #[doc = ""]
+  #[doc = " @param[in]  partition_label   Label of the partition. Must be no longer than 16 characters."]
+  pub fn nvs_flash_init_partition(partition_label: *const ::cty::c_char) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief Deinitialize NVS storage for the default NVS partition"]
+  #[doc = ""]
+  #[doc = " Default NVS partition is the partition with \"nvs\" label in the partition table."]
+  pub fn nvs_flash_deinit() -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief Deinitialize NVS storage for the given NVS partition"]
+  pub fn nvs_flash_deinit_partition(partition_label: *const ::cty::c_char) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief Erase the default NVS partition"]
+  #[doc = ""]
+  #[doc = " Erases all contents of the default NVS partition (one with label \"nvs\")."]
+  #[doc = ""]
+  #[doc = " @note If the partition is initialized, this function first de-initializes it. Afterwards, the partition has to"]
+  #[doc = "       be initialized again to be used."]
+  pub fn nvs_flash_erase() -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief Erase specified NVS partition"]
+  #[doc = ""]
+  #[doc = " Erase all content of a specified NVS partition"]
+  #[doc = ""]
+  #[doc = " @note If the partition is initialized, this function first de-initializes it. Afterwards, the partition has to"]
+  #[doc = "       be initialized again to be used."]
+  pub fn nvs_flash_erase_partition(part_name: *const ::cty::c_char) -> esp_err_t;
+}
@@ -0,0 +1,52 @@
+#![no_std]
+
+// nvs.h
+pub const ESP_ERR_NVS_BASE: esp_err_t = 0x1100;
+/// The storage driver is not initialized
+pub const ESP_ERR_NVS_NOT_INITIALIZED: esp_err_t = ESP_ERR_NVS_BASE + 0x01;
+/// Id namespace doesn’t exist yet and mode is NVS_READONLY
+pub const ESP_ERR_NVS_NOT_FOUND: esp_err_t = ESP_ERR_NVS_BASE + 0x02;
+/// The type of set or get operation doesn't match the type of value stored in NVS
+pub const ESP_ERR_NVS_TYPE_MISMATCH: esp_err_t = ESP_ERR_NVS_BASE + 0x03;
+/// Storage handle was opened as read only
+pub const ESP_ERR_NVS_READ_ONLY: esp_err_t = ESP_ERR_NVS_BASE + 0x04;
+/// There is not enough space in the underlying storage to save the value
+pub const ESP_ERR_NVS_NOT_ENOUGH_SPACE: esp_err_t = ESP_ERR_NVS_BASE + 0x05;
+/// Namespace name doesn’t satisfy constraints
+pub const ESP_ERR_NVS_INVALID_NAME: esp_err_t = ESP_ERR_NVS_BASE + 0x06;
+/// Handle has been closed or is NULL
+pub const ESP_ERR_NVS_INVALID_HANDLE: esp_err_t = ESP_ERR_NVS_BASE + 0x07;
+/// The value wasn’t updated because flash write operation has failed. The value was written
+/// however, and update will be finished after re-initialization of nvs, provided that flash
+/// operation doesn’t fail again.
+pub const ESP_ERR_NVS_REMOVE_FAILED: esp_err_t = ESP_ERR_NVS_BASE + 0x08;
+/// Key name is too long
+pub const ESP_ERR_NVS_KEY_TOO_LONG: esp_err_t = ESP_ERR_NVS_BASE + 0x09;
+/// Internal error; never returned by nvs API functions
+pub const ESP_ERR_NVS_PAGE_FULL: esp_err_t = ESP_ERR_NVS_BASE + 0x0a;
+/// NVS is in an inconsistent state due to a previous error. Call nvs_flash_init and nvs_open
+/// again, then retry.
+pub const ESP_ERR_NVS_INVALID_STATE: esp_err_t = ESP_ERR_NVS_BASE + 0x0b;
+/// String or blob length is not sufficient to store data
+pub const ESP_ERR_NVS_INVALID_LENGTH: esp_err_t = ESP_ERR_NVS_BASE + 0x0c;
+/// NVS partition doesn't contain any empty pages. This may happen if NVS partition was
+/// truncated. Erase the whole partition and call nvs_flash_init again.
+pub const ESP_ERR_NVS_NO_FREE_PAGES: esp_err_t = ESP_ERR_NVS_BASE + 0x0d;
+/// String or blob length is longer than supported by the implementation
+pub const ESP_ERR_NVS_VALUE_TOO_LONG: esp_err_t = ESP_ERR_NVS_BASE + 0x0e;
+/// Partition with specified name is not found in the partition table
+pub const ESP_ERR_NVS_PART_NOT_FOUND: esp_err_t = ESP_ERR_NVS_BASE + 0x0f;
+/// NVS partition contains data in new format and cannot be recognized by this version of code
+pub const ESP_ERR_NVS_NEW_VERSION_FOUND: esp_err_t = ESP_ERR_NVS_BASE + 0x10;
+
+/// Maximal length of NVS key name (including null-terminator)
+pub const NVS_KEY_NAME_MAX_SIZE: usize = 16;
+/// Maximum length of partition name (excluding null terminator)
+pub const NVS_PART_NAME_MAX_SIZE: usize = 16;
+
+// nvs_flash.h
+pub const NVS_DEFAULT_PART_NAME: &str = "nvs";
+
+mod bindings;
+
+pub use bindings::*;
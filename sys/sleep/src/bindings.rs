@@ -0,0 +1,362 @@
+/* automatically generated by rust-bindgen 0.56.0 */
+
+#![allow(non_camel_case_types, non_upper_case_globals)]
+
+pub type esp_err_t = i32;
+#[doc = "< Use to signal not connected to S/W"]
+pub const gpio_num_t_GPIO_NUM_NC: gpio_num_t = -1;
+#[doc = "< GPIO0, input and output"]
+pub const gpio_num_t_GPIO_NUM_0: gpio_num_t = 0;
+#[doc = "< GPIO1, input and output"]
+pub const gpio_num_t_GPIO_NUM_1: gpio_num_t = 1;
+#[doc = "< GPIO2, input and output"]
+pub const gpio_num_t_GPIO_NUM_2: gpio_num_t = 2;
+#[doc = "< GPIO3, input and output"]
+pub const gpio_num_t_GPIO_NUM_3: gpio_num_t = 3;
+#[doc = "< GPIO4, input and output"]
+pub const gpio_num_t_GPIO_NUM_4: gpio_num_t = 4;
+#[doc = "< GPIO5, input and output"]
+pub const gpio_num_t_GPIO_NUM_5: gpio_num_t = 5;
+#[doc = "< GPIO6, input and output"]
+pub const gpio_num_t_GPIO_NUM_6: gpio_num_t = 6;
+#[doc = "< GPIO7, input and output"]
+pub const gpio_num_t_GPIO_NUM_7: gpio_num_t = 7;
+#[doc = "< GPIO8, input and output"]
+pub const gpio_num_t_GPIO_NUM_8: gpio_num_t = 8;
+#[doc = "< GPIO9, input and output"]
+pub const gpio_num_t_GPIO_NUM_9: gpio_num_t = 9;
+#[doc = "< GPIO10, input and output"]
+pub const gpio_num_t_GPIO_NUM_10: gpio_num_t = 10;
+#[doc = "< GPIO11, input and output"]
+pub const gpio_num_t_GPIO_NUM_11: gpio_num_t = 11;
+#[doc = "< GPIO12, input and output"]
+pub const gpio_num_t_GPIO_NUM_12: gpio_num_t = 12;
+#[doc = "< GPIO13, input and output"]
+pub const gpio_num_t_GPIO_NUM_13: gpio_num_t = 13;
+#[doc = "< GPIO14, input and output"]
+pub const gpio_num_t_GPIO_NUM_14: gpio_num_t = 14;
+#[doc = "< GPIO15, input and output"]
+pub const gpio_num_t_GPIO_NUM_15: gpio_num_t = 15;
+#[doc = "< GPIO16, input and output"]
+pub const gpio_num_t_GPIO_NUM_16: gpio_num_t = 16;
+#[doc = "< GPIO17, input and output"]
+pub const gpio_num_t_GPIO_NUM_17: gpio_num_t = 17;
+#[doc = "< GPIO18, input and output"]
+pub const gpio_num_t_GPIO_NUM_18: gpio_num_t = 18;
+#[doc = "< GPIO19, input and output"]
+pub const gpio_num_t_GPIO_NUM_19: gpio_num_t = 19;
+#[doc = "< GPIO21, input and output"]
+pub const gpio_num_t_GPIO_NUM_21: gpio_num_t = 21;
+#[doc = "< GPIO22, input and output"]
+pub const gpio_num_t_GPIO_NUM_22: gpio_num_t = 22;
+#[doc = "< GPIO23, input and output"]
+pub const gpio_num_t_GPIO_NUM_23: gpio_num_t = 23;
+#[doc = "< GPIO25, input and output"]
+pub const gpio_num_t_GPIO_NUM_25: gpio_num_t = 25;
+#[doc = "< GPIO26, input and output"]
+pub const gpio_num_t_GPIO_NUM_26: gpio_num_t = 26;
+#[doc = "< GPIO27, input and output"]
+pub const gpio_num_t_GPIO_NUM_27: gpio_num_t = 27;
+#[doc = "< GPIO32, input and output"]
+pub const gpio_num_t_GPIO_NUM_32: gpio_num_t = 32;
+#[doc = "< GPIO33, input and output"]
+pub const gpio_num_t_GPIO_NUM_33: gpio_num_t = 33;
+#[doc = "< GPIO34, input mode only"]
+pub const gpio_num_t_GPIO_NUM_34: gpio_num_t = 34;
+#[doc = "< GPIO35, input mode only"]
+pub const gpio_num_t_GPIO_NUM_35: gpio_num_t = 35;
+#[doc = "< GPIO36, input mode only"]
+pub const gpio_num_t_GPIO_NUM_36: gpio_num_t = 36;
+#[doc = "< GPIO37, input mode only"]
+pub const gpio_num_t_GPIO_NUM_37: gpio_num_t = 37;
+#[doc = "< GPIO38, input mode only"]
+pub const gpio_num_t_GPIO_NUM_38: gpio_num_t = 38;
+#[doc = "< GPIO39, input mode only"]
+pub const gpio_num_t_GPIO_NUM_39: gpio_num_t = 39;
+#[doc = "< GPIO pin number"]
+pub const gpio_num_t_GPIO_NUM_MAX: gpio_num_t = 40;
+pub type gpio_num_t = ::cty::c_int;
+#[doc = "< Touch pad channel 0 is GPIO4"]
+pub const touch_pad_t_TOUCH_PAD_NUM0: touch_pad_t = 0;
+#[doc = "< Touch pad channel 1 is GPIO0"]
+pub const touch_pad_t_TOUCH_PAD_NUM1: touch_pad_t = 1;
+#[doc = "< Touch pad channel 2 is GPIO2"]
+pub const touch_pad_t_TOUCH_PAD_NUM2: touch_pad_t = 2;
+#[doc = "< Touch pad channel 3 is GPIO15"]
+pub const touch_pad_t_TOUCH_PAD_NUM3: touch_pad_t = 3;
+#[doc = "< Touch pad channel 4 is GPIO13"]
+pub const touch_pad_t_TOUCH_PAD_NUM4: touch_pad_t = 4;
+#[doc = "< Touch pad channel 5 is GPIO12"]
+pub const touch_pad_t_TOUCH_PAD_NUM5: touch_pad_t = 5;
+#[doc = "< Touch pad channel 6 is GPIO14"]
+pub const touch_pad_t_TOUCH_PAD_NUM6: touch_pad_t = 6;
+#[doc = "< Touch pad channel 7 is GPIO27"]
+pub const touch_pad_t_TOUCH_PAD_NUM7: touch_pad_t = 7;
+#[doc = "< Touch pad channel 8 is GPIO33"]
+pub const touch_pad_t_TOUCH_PAD_NUM8: touch_pad_t = 8;
+#[doc = "< Touch pad channel 9 is GPIO32"]
+pub const touch_pad_t_TOUCH_PAD_NUM9: touch_pad_t = 9;
+pub const touch_pad_t_TOUCH_PAD_MAX: touch_pad_t = 10;
+pub type touch_pad_t = ::cty::c_uint;
+#[doc = "!< Wake the chip when all selected GPIOs go low"]
+pub const esp_sleep_ext1_wakeup_mode_t_ESP_EXT1_WAKEUP_ALL_LOW: esp_sleep_ext1_wakeup_mode_t = 0;
+#[doc = "!< Wake the chip when any of the selected GPIOs go high"]
+pub const esp_sleep_ext1_wakeup_mode_t_ESP_EXT1_WAKEUP_ANY_HIGH: esp_sleep_ext1_wakeup_mode_t = 1;
+#[doc = " @brief Logic function used for EXT1 wakeup mode."]
+pub type esp_sleep_ext1_wakeup_mode_t = ::cty::c_uint;
+#[doc = "!< RTC IO, sensors and ULP co-processor"]
+pub const esp_sleep_pd_domain_t_ESP_PD_DOMAIN_RTC_PERIPH: esp_sleep_pd_domain_t = 0;
+#[doc = "!< RTC slow memory"]
+pub const esp_sleep_pd_domain_t_ESP_PD_DOMAIN_RTC_SLOW_MEM: esp_sleep_pd_domain_t = 1;
+#[doc = "!< RTC fast memory"]
+pub const esp_sleep_pd_domain_t_ESP_PD_DOMAIN_RTC_FAST_MEM: esp_sleep_pd_domain_t = 2;
+#[doc = "!< XTAL oscillator"]
+pub const esp_sleep_pd_domain_t_ESP_PD_DOMAIN_XTAL: esp_sleep_pd_domain_t = 3;
+#[doc = "!< Number of domains"]
+pub const esp_sleep_pd_domain_t_ESP_PD_DOMAIN_MAX: esp_sleep_pd_domain_t = 4;
+#[doc = " @brief Power domains which can be powered down in sleep mode"]
+pub type esp_sleep_pd_domain_t = ::cty::c_uint;
+#[doc = "!< Power down the power domain in sleep mode"]
+pub const esp_sleep_pd_option_t_ESP_PD_OPTION_OFF: esp_sleep_pd_option_t = 0;
+#[doc = "!< Keep power domain enabled during sleep mode"]
+pub const esp_sleep_pd_option_t_ESP_PD_OPTION_ON: esp_sleep_pd_option_t = 1;
+#[doc = "!< Keep power domain enabled in sleep mode, if it is needed by one of the wakeup options. Otherwise power it down."]
+pub const esp_sleep_pd_option_t_ESP_PD_OPTION_AUTO: esp_sleep_pd_option_t = 2;
+#[doc = " @brief Power down options"]
+pub type esp_sleep_pd_option_t = ::cty::c_uint;
+#[doc = "!< In case of deep sleep, reset was not caused by exit from deep sleep"]
+pub const esp_sleep_source_t_ESP_SLEEP_WAKEUP_UNDEFINED: esp_sleep_source_t = 0;
+#[doc = "!< Not a wakeup cause, used to disable all wakeup sources with esp_sleep_disable_wakeup_source"]
+pub const esp_sleep_source_t_ESP_SLEEP_WAKEUP_ALL: esp_sleep_source_t = 1;
+#[doc = "!< Wakeup caused by external signal using RTC_IO"]
+pub const esp_sleep_source_t_ESP_SLEEP_WAKEUP_EXT0: esp_sleep_source_t = 2;
+#[doc = "!< Wakeup caused by external signal using RTC_CNTL"]
+pub const esp_sleep_source_t_ESP_SLEEP_WAKEUP_EXT1: esp_sleep_source_t = 3;
+#[doc = "!< Wakeup caused by timer"]
+pub const esp_sleep_source_t_ESP_SLEEP_WAKEUP_TIMER: esp_sleep_source_t = 4;
+#[doc = "!< Wakeup caused by touchpad"]
+pub const esp_sleep_source_t_ESP_SLEEP_WAKEUP_TOUCHPAD: esp_sleep_source_t = 5;
+#[doc = "!< Wakeup caused by ULP program"]
+pub const esp_sleep_source_t_ESP_SLEEP_WAKEUP_ULP: esp_sleep_source_t = 6;
+#[doc = "!< Wakeup caused by GPIO (light sleep only)"]
+pub const esp_sleep_source_t_ESP_SLEEP_WAKEUP_GPIO: esp_sleep_source_t = 7;
+#[doc = "!< Wakeup caused by UART (light sleep only)"]
+pub const esp_sleep_source_t_ESP_SLEEP_WAKEUP_UART: esp_sleep_source_t = 8;
+#[doc = " @brief Sleep wakeup cause"]
+pub type esp_sleep_source_t = ::cty::c_uint;
+#[doc = " @brief Sleep wakeup cause"]
+pub use self::esp_sleep_source_t as esp_sleep_wakeup_cause_t;
+extern "C" {
+  #[doc = " @brief Disable wakeup source"]
+  #[doc = ""]
+  #[doc = " This function is used to deactivate wake up trigger for source"]
+  #[doc = " defined as parameter of the function."]
+  #[doc = ""]
+  #[doc = " @note This function does not modify wake up configuration in RTC."]
+  #[doc = "       It will be performed in esp_sleep_start function."]
+  #[doc = ""]
+  #[doc = " See docs/sleep-modes.rst for details."]
+  #[doc = ""]
+  #[doc = " @param source - number of source to disable of type esp_sleep_source_t"]
+  #[doc = " @return"]
+  #[doc = "      - ESP_OK on success"]
+  #[doc = "      - ESP_ERR_INVALID_STATE if trigger was not active"]
+  pub fn esp_sleep_disable_wakeup_source(source: esp_sleep_source_t) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief Enable wakeup by ULP coprocessor"]
+  #[doc = " @note In revisions 0 and 1 of the ESP32, ULP wakeup source"]
+  #[doc = "       cannot be used when RTC_PERIPH power domain is forced"]
+  #[doc = "       to be powered on (ESP_PD_OPTION_ON) or when"]
+  #[doc = "       ext0 wakeup source is used."]
+  #[doc = " @return"]
+  #[doc = "      - ESP_OK on success"]
+  #[doc = "      - ESP_ERR_NOT_SUPPORTED if additional current by touch (CONFIG_ESP32_RTC_EXT_CRYST_ADDIT_CURRENT) is enabled."]
+  #[doc = "      - ESP_ERR_INVALID_STATE if ULP co-processor is not enabled or if wakeup triggers conflict"]
+  pub fn esp_sleep_enable_ulp_wakeup() -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief Enable wakeup by timer"]
+  #[doc = " @param time_in_us  time before wakeup, in microseconds"]
+  #[doc = " @return"]
+  #[doc = "      - ESP_OK on success"]
+  #[doc = "      - ESP_ERR_INVALID_ARG if value is out of range (TBD)"]
+  pub fn esp_sleep_enable_timer_wakeup(time_in_us: u64) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief Enable wakeup by touch sensor"]
+  #[doc = ""]
+  #[doc = " @note In revisions 0 and 1 of the ESP32, touch wakeup source"]
+  #[doc = "       can not be used when RTC_PERIPH power domain is forced"]
+  #[doc = "       to be powered on (ESP_PD_OPTION_ON) or when ext0 wakeup"]
+  #[doc = "       source is used."]
+  #[doc = ""]
+  #[doc = " @note The FSM mode of the touch button should be configured"]
+  #[doc = "       as the timer trigger mode."]
+  #[doc = ""]
+  #[doc = " @return"]
+  #[doc = "      - ESP_OK on success"]
+  #[doc = "      - ESP_ERR_NOT_SUPPORTED if additional current by touch (CONFIG_ESP32_RTC_EXT_CRYST_ADDIT_CURRENT) is enabled."]
+  #[doc = "      - ESP_ERR_INVALID_STATE if wakeup triggers conflict"]
+  pub fn esp_sleep_enable_touchpad_wakeup() -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief Get the touch pad which caused wakeup"]
+  #[doc = ""]
+  #[doc = " If wakeup was caused by another source, this function will return TOUCH_PAD_MAX;"]
+  #[doc = ""]
+  #[doc = " @return touch pad which caused wakeup"]
+  pub fn esp_sleep_get_touchpad_wakeup_status() -> touch_pad_t;
+}
+extern "C" {
+  #[doc = " @brief Returns true if a GPIO number is valid for use as wakeup source."]
+  #[doc = ""]
+  #[doc = " @note For SoCs with RTC IO capability, this can be any valid RTC IO input pin."]
+  #[doc = ""]
+  #[doc = " @param gpio_num Number of the GPIO to test for wakeup source capability"]
+  #[doc = ""]
+  #[doc = " @return True if this GPIO number will be accepted as a sleep wakeup source."]
+  pub fn esp_sleep_is_valid_wakeup_gpio(gpio_num: gpio_num_t) -> bool;
+}
+extern "C" {
+  #[doc = " @brief Enable wakeup using a pin"]
+  #[doc = ""]
+  #[doc = " This function uses external wakeup feature of RTC_IO peripheral."]
+  #[doc = " It will work only if RTC peripherals are kept on during sleep."]
+  #[doc = ""]
+  #[doc = " This feature can monitor any pin which is an RTC IO. Once the pin transitions"]
+  #[doc = " into the state given by level argument, the chip will be woken up."]
+  #[doc = ""]
+  #[doc = " @note This function does not modify pin configuration. The pin is"]
+  #[doc = "       configured in esp_sleep_start, immediately before entering sleep mode."]
+  #[doc = ""]
+  #[doc = " @note In revisions 0 and 1 of the ESP32, ext0 wakeup source"]
+  #[doc = "       can not be used together with touch or ULP wakeup sources."]
+  #[doc = ""]
+  #[doc = " @param gpio_num  GPIO number used as wakeup source. Only GPIOs which are have RTC"]
+  #[doc = "             functionality can be used: 0,2,4,12-15,25-27,32-39."]
+  #[doc = " @param level  input level which will trigger wakeup (0=low, 1=high)"]
+  #[doc = " @return"]
+  #[doc = "      - ESP_OK on success"]
+  #[doc = "      - ESP_ERR_INVALID_ARG if the selected GPIO is not an RTC GPIO,"]
+  #[doc = "        or the mode is invalid"]
+  #[doc = "      - ESP_ERR_INVALID_STATE if wakeup triggers conflict"]
+  pub fn esp_sleep_enable_ext0_wakeup(gpio_num: gpio_num_t, level: ::cty::c_int) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief Enable wakeup using multiple pins"]
+  #[doc = ""]
+  #[doc = " This function uses external wakeup feature of RTC controller."]
+  #[doc = " It will work even if RTC peripherals are shut down during sleep."]
+  #[doc = ""]
+  #[doc = " This feature can monitor any number of pins which are in RTC IOs."]
+  #[doc = " Once any of the selected pins goes into the state given by mode argument,"]
+  #[doc = " the chip will be woken up."]
+  #[doc = ""]
+  #[doc = " @note This function does not modify pin configuration. The pins are"]
+  #[doc = "       configured in esp_sleep_start, immediately before"]
+  #[doc = "       entering sleep mode."]
+  #[doc = ""]
+  #[doc = " @note internal pullups and pulldowns don't work when RTC peripherals are"]
+  #[doc = "       shut down. In this case, external resistors need to be added."]
+  #[doc = "       Alternatively, RTC peripherals (and pullups/pulldowns) can be"]
+  #[doc = "       kept enabled using esp_sleep_pd_config function."]
+  #[doc = ""]
+  #[doc = " @param mask  bit mask of GPIO numbers which will cause wakeup. Only GPIOs"]
+  #[doc = "              which are have RTC functionality can be used in this bit map:"]
+  #[doc = "              0,2,4,12-15,25-27,32-39."]
+  #[doc = " @param mode select logic function used to determine wakeup condition:"]
+  #[doc = "            - ESP_EXT1_WAKEUP_ALL_LOW: wake up when all selected GPIOs are low"]
+  #[doc = "            - ESP_EXT1_WAKEUP_ANY_HIGH: wake up when any of the selected GPIOs is high"]
+  #[doc = " @return"]
+  #[doc = "      - ESP_OK on success"]
+  #[doc = "      - ESP_ERR_INVALID_ARG if any of the selected GPIOs is not an RTC GPIO,"]
+  #[doc = "        or mode is invalid"]
+  pub fn esp_sleep_enable_ext1_wakeup(mask: u64, mode: esp_sleep_ext1_wakeup_mode_t) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief Enable wakeup from light sleep using GPIOs"]
+  #[doc = ""]
+  #[doc = " Each GPIO supports wakeup function, which can be triggered on either low level"]
+  #[doc = " or high level. Unlike EXT0 and EXT1 wakeup sources, this method can be used"]
+  #[doc = " both for all IOs: RTC IOs and digital IOs. It can only be used to wakeup from"]
+  #[doc = " light sleep though."]
+  #[doc = ""]
+  #[doc = " To enable wakeup, first call gpio_wakeup_enable, specifying gpio number and"]
+  #[doc = " wakeup level, for each GPIO which is used for wakeup."]
+  #[doc = " Then call this function to enable wakeup feature."]
+  #[doc = ""]
+  #[doc = " @note In revisions 0 and 1 of the ESP32, GPIO wakeup source"]
+  #[doc = "       can not be used together with touch or ULP wakeup sources."]
+  #[doc = ""]
+  #[doc = " @return"]
+  #[doc = "      - ESP_OK on success"]
+  #[doc = "      - ESP_ERR_INVALID_STATE if wakeup triggers conflict"]
+  pub fn esp_sleep_enable_gpio_wakeup() -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief Get the bit mask of GPIOs which caused wakeup (ext1)"]
+  #[doc = ""]
+  #[doc = " If wakeup was caused by another source, this function will return 0."]
+  #[doc = ""]
+  #[doc = " @return bit mask, if GPIOn caused wakeup, BIT(n) will be set"]
+  pub fn esp_sleep_get_ext1_wakeup_status() -> u64;
+}
+extern "C" {
+  #[doc = " @brief Set power down mode for an RTC power domain in sleep mode"]
+  #[doc = ""]
+  #[doc = " If not set set using this API, all power domains default to ESP_PD_OPTION_AUTO."]
+  #[doc = ""]
+  #[doc = " @param domain  power domain to configure"]
+  #[doc = " @param option  power down option (ESP_PD_OPTION_OFF, ESP_PD_OPTION_ON, or ESP_PD_OPTION_AUTO)"]
+  #[doc = " @return"]
+  #[doc = "      - ESP_OK on success"]
+  #[doc = "      - ESP_ERR_INVALID_ARG if either of the arguments is out of range"]
+  pub fn esp_sleep_pd_config(
+    domain: esp_sleep_pd_domain_t,
+    option: esp_sleep_pd_option_t,
+  ) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief Enter deep sleep with the configured wakeup options"]
+  #[doc = ""]
+  #[doc = " This function does not return."]
+  pub fn esp_deep_sleep_start();
+}
+extern "C" {
+  #[doc = " @brief Enter light sleep with the configured wakeup options"]
+  #[doc = ""]
+  #[doc = " @return"]
+  #[doc = "  - ESP_OK on success (returned after wakeup)"]
+  #[doc = "  - ESP_ERR_INVALID_STATE if WiFi or BT is not stopped"]
+  pub fn esp_light_sleep_start() -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief Enter deep-sleep mode"]
+  #[doc = ""]
+  #[doc = " The device will automatically wake up after the deep-sleep time"]
+  #[doc = " Upon waking up, the device calls deep sleep wake stub, and then proceeds"]
+  #[doc = " to load application."]
+  #[doc = ""]
+  #[doc = " Call to this function is equivalent to a call to esp_deep_sleep_enable_timer_wakeup"]
+  #[doc = " followed by a call to esp_deep_sleep_start."]
+  #[doc = ""]
+  #[doc = " esp_deep_sleep does not shut down WiFi, BT, and higher level protocol"]
+  #[doc = " connections gracefully."]
+  #[doc = " Make sure relevant WiFi and BT stack functions are called to close any"]
+  #[doc = " connections and deinitialize the peripherals. These include:"]
+  #[doc = "     - esp_bluedroid_disable"]
+  #[doc = "     - esp_bt_controller_disable"]
+  #[doc = "     - esp_wifi_stop"]
+  #[doc = ""]
+  #[doc = " This function does not return."]
+  #[doc = ""]
+  #[doc = " @param time_in_us  deep-sleep time, unit: microsecond"]
+  pub fn esp_deep_sleep(time_in_us: u64);
+}
+extern "C" {
+  #[doc = " @brief Get the wakeup source which caused wakeup from sleep"]
+  #[doc = ""]
+  #[doc = " @return cause of wake up from last sleep (deep sleep or light sleep)"]
+  pub fn esp_sleep_get_wakeup_cause() -> esp_sleep_wakeup_cause_t;
+}
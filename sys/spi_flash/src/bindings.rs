@@ -0,0 +1,47 @@
+/* automatically generated by rust-bindgen 0.56.0 */
+
+#![allow(non_camel_case_types, non_upper_case_globals)]
+
+pub type esp_err_t = i32;
+extern "C" {
+  #[doc = " @brief  Get flash chip size, as set in binary image header"]
+  #[doc = ""]
+  #[doc = " @note This value does not necessarily match real flash size."]
+  #[doc = ""]
+  #[doc = " @return size of flash chip, in bytes"]
+  pub fn spi_flash_get_chip_size() -> usize;
+}
+#[doc = " @brief Structure for describing a SPI flash chip connected to the system."]
+#[doc = ""]
+#[doc = "Structure must be initialized before use (passed to esp_flash_init()). It's recommended to initialize"]
+#[doc = "it with ``esp_flash_init`` or ``spi_bus_add_flash_device``."]
+#[repr(C)]
+#[repr(align(8))]
+#[derive(Debug, Copy, Clone)]
+pub struct esp_flash_t {
+  pub _bindgen_opaque_blob: [u64; 6usize],
+}
+#[test]
+fn bindgen_test_layout_esp_flash_t() {
+  assert_eq!(
+    ::core::mem::size_of::<esp_flash_t>(),
+    48usize,
+    concat!("Size of: ", stringify!(esp_flash_t))
+  );
+  assert_eq!(
+    ::core::mem::align_of::<esp_flash_t>(),
+    8usize,
+    concat!("Alignment of ", stringify!(esp_flash_t))
+  );
+}
+extern "C" {
+  #[doc = " @brief Read flash ID via the common \"RDID\" SPI flash command."]
+  #[doc = ""]
+  #[doc = " @param chip Pointer to identify flash chip. Must have been successfully initialised via esp_flash_init()"]
+  #[doc = " @param[out] out_id Pointer to receive ID value."]
+  #[doc = ""]
+  #[doc = " ID is a 24-bit value. Lower 16 bits of 'id' are the chip ID, upper 8 bits are the manufacturer ID."]
+  #[doc = ""]
+  #[doc = " @return ESP_OK on success, or a flash error code if operation failed."]
+  pub fn esp_flash_read_id(chip: *mut esp_flash_t, out_id: *mut u32) -> esp_err_t;
+}
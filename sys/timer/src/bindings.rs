@@ -0,0 +1,170 @@
+/* automatically generated by rust-bindgen 0.56.0 */
+
+#![allow(non_camel_case_types, non_upper_case_globals)]
+
+pub type esp_err_t = i32;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct esp_timer {
+  _unused: [u8; 0],
+}
+#[doc = " @brief Opaque type representing a single esp_timer"]
+pub type esp_timer_handle_t = *mut esp_timer;
+#[doc = " @brief Timer callback function type"]
+#[doc = " @param arg pointer to opaque user-specific data"]
+pub type esp_timer_cb_t = ::core::option::Option<unsafe extern "C" fn(arg: *mut ::cty::c_void)>;
+#[doc = "!< Callback is called from timer task"]
+pub const esp_timer_dispatch_t_ESP_TIMER_TASK: esp_timer_dispatch_t = 0;
+#[doc = " @brief Method for dispatching timer callback"]
+pub type esp_timer_dispatch_t = ::cty::c_uint;
+#[doc = " @brief Timer configuration passed to esp_timer_create"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct esp_timer_create_args_t {
+  #[doc = "!< Function to call when timer expires"]
+  pub callback: esp_timer_cb_t,
+  #[doc = "!< Argument to pass to the callback"]
+  pub arg: *mut ::cty::c_void,
+  #[doc = "!< Call the callback from task or from ISR"]
+  pub dispatch_method: esp_timer_dispatch_t,
+  #[doc = "!< Timer name, used in esp_timer_dump function"]
+  pub name: *const ::cty::c_char,
+}
+#[test]
+fn bindgen_test_layout_esp_timer_create_args_t() {
+  assert_eq!(
+    ::core::mem::size_of::<esp_timer_create_args_t>(),
+    32usize,
+    concat!("Size of: ", stringify!(esp_timer_create_args_t))
+  );
+  assert_eq!(
+    ::core::mem::align_of::<esp_timer_create_args_t>(),
+    8usize,
+    concat!("Alignment of ", stringify!(esp_timer_create_args_t))
+  );
+  assert_eq!(
+    unsafe { &(*(::core::ptr::null::<esp_timer_create_args_t>())).callback as *const _ as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(esp_timer_create_args_t),
+      "::",
+      stringify!(callback)
+    )
+  );
+  assert_eq!(
+    unsafe { &(*(::core::ptr::null::<esp_timer_create_args_t>())).arg as *const _ as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(esp_timer_create_args_t),
+      "::",
+      stringify!(arg)
+    )
+  );
+  assert_eq!(
+    unsafe {
+      &(*(::core::ptr::null::<esp_timer_create_args_t>())).dispatch_method as *const _ as usize
+    },
+    16usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(esp_timer_create_args_t),
+      "::",
+      stringify!(dispatch_method)
+    )
+  );
+  assert_eq!(
+    unsafe { &(*(::core::ptr::null::<esp_timer_create_args_t>())).name as *const _ as usize },
+    24usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(esp_timer_create_args_t),
+      "::",
+      stringify!(name)
+    )
+  );
+}
+extern "C" {
+  #[doc = " @brief Create an esp_timer instance"]
+  #[doc = ""]
+  #[doc = " @note When done using the timer, delete it with esp_timer_delete function."]
+  #[doc = ""]
+  #[doc = " @param create_args   Pointer to a structure with timer creation arguments."]
+  #[doc = "                      Not saved by the library, can be allocated on the stack."]
+  #[doc = " @param[out] out_handle  Output, pointer to esp_timer_handle_t variable which"]
+  #[doc = "                         will hold the created timer handle."]
+  #[doc = ""]
+  #[doc = " @return"]
+  #[doc = "      - ESP_OK on success"]
+  #[doc = "      - ESP_ERR_INVALID_ARG if some of the create_args are not valid"]
+  #[doc = "      - ESP_ERR_INVALID_STATE if esp_timer library is not initialized yet"]
+  #[doc = "      - ESP_ERR_NO_MEM if memory allocation fails"]
+  pub fn esp_timer_create(
+    create_args: *const esp_timer_create_args_t,
+    out_handle: *mut esp_timer_handle_t,
+  ) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief Start one-shot timer"]
+  #[doc = ""]
+  #[doc = " Timer should not be running when this function is called."]
+  #[doc = ""]
+  #[doc = " @param timer timer handle created using esp_timer_create"]
+  #[doc = " @param timeout_us timer timeout, in microseconds relative to the current moment"]
+  #[doc = " @return"]
+  #[doc = "      - ESP_OK on success"]
+  #[doc = "      - ESP_ERR_INVALID_ARG if the handle is invalid"]
+  #[doc = "      - ESP_ERR_INVALID_STATE if the timer is already running"]
+  pub fn esp_timer_start_once(timer: esp_timer_handle_t, timeout_us: u64) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief Start a periodic timer"]
+  #[doc = ""]
+  #[doc = " Timer should not be running when this function is called. This function will"]
+  #[doc = " start the timer which will trigger every 'period' microseconds."]
+  #[doc = ""]
+  #[doc = " @param timer timer handle created using esp_timer_create"]
+  #[doc = " @param period timer period, in microseconds"]
+  #[doc = " @return"]
+  #[doc = "      - ESP_OK on success"]
+  #[doc = "      - ESP_ERR_INVALID_ARG if the handle is invalid"]
+  #[doc = "      - ESP_ERR_INVALID_STATE if the timer is already running"]
+  pub fn esp_timer_start_periodic(timer: esp_timer_handle_t, period: u64) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief Stop the timer"]
+  #[doc = ""]
+  #[doc = " This function stops the timer previously started using esp_timer_start_once"]
+  #[doc = " or esp_timer_start_periodic."]
+  #[doc = ""]
+  #[doc = " @param timer timer handle created using esp_timer_create"]
+  #[doc = " @return"]
+  #[doc = "      - ESP_OK on success"]
+  #[doc = "      - ESP_ERR_INVALID_STATE if the timer is not running"]
+  pub fn esp_timer_stop(timer: esp_timer_handle_t) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief Delete an esp_timer instance"]
+  #[doc = ""]
+  #[doc = " The timer must be stopped before deleting. A one-shot timer which has expired"]
+  #[doc = " does not need to be stopped."]
+  #[doc = ""]
+  #[doc = " @param timer timer handle allocated using esp_timer_create"]
+  #[doc = " @return"]
+  #[doc = "      - ESP_OK on success"]
+  #[doc = "      - ESP_ERR_INVALID_STATE if the timer is running"]
+  pub fn esp_timer_delete(timer: esp_timer_handle_t) -> esp_err_t;
+}
+extern "C" {
+  #[doc = " @brief Get time in microseconds since boot"]
+  #[doc = " @return number of microseconds since esp_timer_init was called (this normally"]
+  #[doc = "          happens early during application startup)."]
+  pub fn esp_timer_get_time() -> i64;
+}
+extern "C" {
+  #[doc = " @brief Get the timestamp when the next timeout is expected to occur"]
+  #[doc = " @return Timestamp of the nearest timer event, in microseconds."]
+  #[doc = "         The timebase is the same as for the values returned by esp_timer_get_time."]
+  pub fn esp_timer_get_next_alarm() -> i64;
+}
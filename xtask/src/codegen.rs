@@ -8,6 +8,16 @@ struct Config {
   component: String,
   headers: Vec<String>,
   functions: Vec<String>,
+  #[serde(default)]
+  variables: Vec<String>,
+  #[serde(default)]
+  opaque_types: Vec<String>,
+  #[serde(default)]
+  defines: Vec<String>,
+  #[serde(default)]
+  blacklist_types: Vec<String>,
+  #[serde(default)]
+  raw_lines: Vec<String>,
 }
 
 pub fn gen_bindings(conf_file: &Path, idf_path: &Path, includes: &[&Path]) -> Result<()> {
@@ -41,9 +51,28 @@ pub fn gen_bindings(conf_file: &Path, idf_path: &Path, includes: &[&Path]) -> Re
     builder = builder.whitelist_function(fun);
   }
 
+  // constants, including sdkconfig.h settings
+  for var in config.variables {
+    builder = builder.whitelist_var(var);
+  }
+
+  for ty in config.opaque_types {
+    builder = builder.opaque_type(ty);
+  }
+
+  // blacklisted types are written by hand in the crate, and imported with `raw_lines`
+  for ty in config.blacklist_types {
+    builder = builder.blacklist_type(ty);
+  }
+
+  for line in config.raw_lines {
+    builder = builder.raw_line(line);
+  }
+
   let out = builder
     .clang_arg("-D__GLIBC_USE(x)=0")
     .clang_arg("-DSSIZE_MAX")
+    .clang_args(config.defines.iter().map(|d| format!("-D{}", d)))
     .clang_args(includes.iter().map(|i| format!("-I{}", i.display())))
     .generate_block(true)
     .generate()